@@ -0,0 +1,103 @@
+use super::Rgba;
+
+/// Separable blend modes as defined by the W3C compositing specification.
+///
+/// Blending is only correct on linear colors.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub enum BlendMode {
+    /// The source replaces the backdrop
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    HardLight,
+    SoftLight,
+    Difference,
+
+    /// Sum of both colors, clamped to one
+    Add,
+
+    /// Backdrop minus source, clamped to zero
+    Subtract,
+}
+
+
+impl BlendMode {
+    /// Blend a source channel onto a backdrop channel, both in `[0, 1]` and fully opaque
+    pub fn apply(self, backdrop: f32, source: f32) -> f32 {
+        match self {
+            BlendMode::Normal => source,
+            BlendMode::Multiply => backdrop * source,
+            BlendMode::Screen => backdrop + source - backdrop * source,
+            BlendMode::Overlay => BlendMode::HardLight.apply(source, backdrop),
+            BlendMode::Darken => backdrop.min(source),
+            BlendMode::Lighten => backdrop.max(source),
+            BlendMode::HardLight => if source <= 0.5 {
+                BlendMode::Multiply.apply(backdrop, 2.0 * source)
+            } else {
+                BlendMode::Screen.apply(backdrop, 2.0 * source - 1.0)
+            },
+            BlendMode::SoftLight => {
+                if source <= 0.5 {
+                    backdrop - (1.0 - 2.0 * source) * backdrop * (1.0 - backdrop)
+                } else {
+                    let d = if backdrop <= 0.25 {
+                        ((16.0 * backdrop - 12.0) * backdrop + 4.0) * backdrop
+                    } else {
+                        backdrop.sqrt()
+                    };
+                    backdrop + (2.0 * source - 1.0) * (d - backdrop)
+                }
+            }
+            BlendMode::Difference => (backdrop - source).abs(),
+            BlendMode::Add => (backdrop + source).min(1.0),
+            BlendMode::Subtract => (backdrop - source).max(0.0),
+        }
+    }
+}
+
+
+impl Rgba {
+    /// Blend this color onto a backdrop and composite the result with source-over.
+    ///
+    /// Both colors have straight alpha, as does the result.
+    pub fn blend(&self, backdrop: Rgba, mode: BlendMode) -> Rgba {
+        let alpha = self.a + backdrop.a * (1.0 - self.a);
+        if alpha == 0.0 {
+            return Rgba::new(0.0, 0.0, 0.0, 0.0);
+        }
+
+        // Where the backdrop is transparent the source shows unblended
+        let channel = |b: f32, s: f32| {
+            let blended = (1.0 - backdrop.a) * s + backdrop.a * mode.apply(b, s);
+            (self.a * blended + backdrop.a * b * (1.0 - self.a)) / alpha
+        };
+
+        Rgba::new(
+            channel(backdrop.r, self.r),
+            channel(backdrop.g, self.g),
+            channel(backdrop.b, self.b),
+            alpha,
+        )
+    }
+
+    /// Composite this color over a backdrop, both with straight alpha
+    pub fn over(&self, backdrop: Rgba) -> Rgba {
+        self.blend(backdrop, BlendMode::Normal)
+    }
+
+    /// Composite this color over a backdrop, both with premultiplied alpha
+    pub fn over_premultiplied(&self, backdrop: Rgba) -> Rgba {
+        let t = 1.0 - self.a;
+        Rgba::new(
+            self.r + backdrop.r * t,
+            self.g + backdrop.g * t,
+            self.b + backdrop.b * t,
+            self.a + backdrop.a * t,
+        )
+    }
+}
@@ -0,0 +1,21 @@
+//! Colors, transfer functions and color spaces
+mod rgb;
+pub use self::rgb::{
+    Rgb,
+    Rgba,
+    Rgb8,
+    Rgba8,
+    ParseColorError,
+    srgb_to_linear,
+    linear_to_srgb,
+};
+
+mod blend;
+pub use self::blend::BlendMode;
+
+mod space;
+pub use self::space::{
+    Hsv,
+    Hsl,
+    Oklab,
+};
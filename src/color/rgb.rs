@@ -0,0 +1,385 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use linear::{Vector3, Vector4};
+
+/// Decode an sRGB-encoded channel in `[0, 1]` to linear light
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encode a linear light channel in `[0, 1]` with the sRGB transfer function
+pub fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Quantize a channel in `[0, 1]` to a byte, clamping out of range values
+fn to_byte(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn from_byte(value: u8) -> f32 {
+    value as f32 / 255.0
+}
+
+
+/// A color with floating point channels, usually in `[0, 1]`.
+///
+/// Whether the channels are sRGB-encoded or linear is up to the user, convert with
+/// `srgb_to_linear` and `linear_to_srgb`. Lighting and blending should happen in linear.
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug, Default)]
+pub struct Rgb {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+
+/// A color with floating point channels and straight (not premultiplied) alpha
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug, Default)]
+pub struct Rgba {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+
+/// An sRGB-encoded color with 8 bit channels
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct Rgb8 {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+
+/// An sRGB-encoded color with 8 bit channels and straight linear alpha
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct Rgba8 {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+
+/// Why a hex color could not be parsed
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+pub enum ParseColorError {
+    /// The number of digits is not supported
+    Length,
+
+    /// A character is not a hexadecimal digit
+    Digit,
+}
+
+
+impl Rgb {
+    /// Create a new color
+    pub fn new(r: f32, g: f32, b: f32) -> Rgb {
+        Rgb { r, g, b }
+    }
+
+    /// Add an alpha channel
+    pub fn with_alpha(&self, a: f32) -> Rgba {
+        Rgba::new(self.r, self.g, self.b, a)
+    }
+
+
+    /// Decode from sRGB to linear
+    pub fn srgb_to_linear(&self) -> Rgb {
+        Rgb::new(srgb_to_linear(self.r), srgb_to_linear(self.g), srgb_to_linear(self.b))
+    }
+
+    /// Encode from linear to sRGB
+    pub fn linear_to_srgb(&self) -> Rgb {
+        Rgb::new(linear_to_srgb(self.r), linear_to_srgb(self.g), linear_to_srgb(self.b))
+    }
+
+    /// Encode a linear color as sRGB bytes
+    pub fn to_srgb8(&self) -> Rgb8 {
+        self.linear_to_srgb().into()
+    }
+
+
+    /// Relative luminance of a linear color
+    pub fn luminance(&self) -> f32 {
+        0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
+    }
+
+    /// Linear interpolation per channel
+    pub fn lerp(&self, other: Rgb, t: f32) -> Rgb {
+        Rgb::new(
+            self.r + (other.r - self.r) * t,
+            self.g + (other.g - self.g) * t,
+            self.b + (other.b - self.b) * t,
+        )
+    }
+}
+
+
+impl Rgba {
+    /// Create a new color
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Rgba {
+        Rgba { r, g, b, a }
+    }
+
+    /// The color channels without alpha
+    pub fn rgb(&self) -> Rgb {
+        Rgb::new(self.r, self.g, self.b)
+    }
+
+
+    /// Decode the color channels from sRGB to linear, alpha is always linear
+    pub fn srgb_to_linear(&self) -> Rgba {
+        self.rgb().srgb_to_linear().with_alpha(self.a)
+    }
+
+    /// Encode the color channels from linear to sRGB, alpha is always linear
+    pub fn linear_to_srgb(&self) -> Rgba {
+        self.rgb().linear_to_srgb().with_alpha(self.a)
+    }
+
+    /// Encode a linear color as sRGB bytes
+    pub fn to_srgb8(&self) -> Rgba8 {
+        self.linear_to_srgb().into()
+    }
+
+
+    /// Multiply the color channels by alpha
+    pub fn premultiply(&self) -> Rgba {
+        Rgba::new(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+    }
+
+    /// Divide the color channels of a premultiplied color by alpha, black if fully transparent
+    pub fn unpremultiply(&self) -> Rgba {
+        if self.a == 0.0 {
+            return Rgba::new(0.0, 0.0, 0.0, 0.0);
+        }
+
+        Rgba::new(self.r / self.a, self.g / self.a, self.b / self.a, self.a)
+    }
+
+
+    /// Linear interpolation per channel
+    pub fn lerp(&self, other: Rgba, t: f32) -> Rgba {
+        self.rgb().lerp(other.rgb(), t).with_alpha(self.a + (other.a - self.a) * t)
+    }
+}
+
+
+impl Rgb8 {
+    /// Create a new color
+    pub fn new(r: u8, g: u8, b: u8) -> Rgb8 {
+        Rgb8 { r, g, b }
+    }
+
+    /// Add an alpha channel
+    pub fn with_alpha(&self, a: u8) -> Rgba8 {
+        Rgba8::new(self.r, self.g, self.b, a)
+    }
+
+    /// Decode to linear floating point channels
+    pub fn to_linear(&self) -> Rgb {
+        Rgb::from(*self).srgb_to_linear()
+    }
+
+
+    /// Parse `#rgb` or `#rrggbb`, the `#` is optional
+    pub fn from_hex(hex: &str) -> Result<Rgb8, ParseColorError> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        match hex.len() {
+            3 | 6 => Rgba8::from_hex(hex).map(|color| color.rgb()),
+            _ => Err(ParseColorError::Length),
+        }
+    }
+
+    /// Format as `#rrggbb`
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+
+impl Rgba8 {
+    /// Create a new color
+    pub fn new(r: u8, g: u8, b: u8, a: u8) -> Rgba8 {
+        Rgba8 { r, g, b, a }
+    }
+
+    /// The color channels without alpha
+    pub fn rgb(&self) -> Rgb8 {
+        Rgb8::new(self.r, self.g, self.b)
+    }
+
+    /// Decode to linear floating point channels
+    pub fn to_linear(&self) -> Rgba {
+        Rgba::from(*self).srgb_to_linear()
+    }
+
+
+    /// Parse `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`, the `#` is optional.
+    ///
+    /// Alpha is opaque when omitted.
+    pub fn from_hex(hex: &str) -> Result<Rgba8, ParseColorError> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+        let mut digits = [0xf_u8; 8];
+        let count = hex.chars().count();
+        if !matches!(count, 3 | 4 | 6 | 8) {
+            return Err(ParseColorError::Length);
+        }
+
+        for (i, c) in hex.chars().enumerate() {
+            digits[i] = c.to_digit(16).ok_or(ParseColorError::Digit)? as u8;
+        }
+
+        let channel = |i: usize| if count <= 4 {
+            digits[i] * 0x11
+        } else {
+            digits[2 * i] << 4 | digits[2 * i + 1]
+        };
+
+        Ok(Rgba8::new(channel(0), channel(1), channel(2), channel(3)))
+    }
+
+    /// Format as `#rrggbbaa`
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+    }
+}
+
+
+impl FromStr for Rgb8 {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rgb8::from_hex(s)
+    }
+}
+
+impl FromStr for Rgba8 {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rgba8::from_hex(s)
+    }
+}
+
+
+// CONVERSION
+
+// Between byte and float channels, without changing the encoding
+
+impl From<Rgb8> for Rgb {
+    fn from(color: Rgb8) -> Self {
+        Rgb::new(from_byte(color.r), from_byte(color.g), from_byte(color.b))
+    }
+}
+
+impl From<Rgb> for Rgb8 {
+    fn from(color: Rgb) -> Self {
+        Rgb8::new(to_byte(color.r), to_byte(color.g), to_byte(color.b))
+    }
+}
+
+impl From<Rgba8> for Rgba {
+    fn from(color: Rgba8) -> Self {
+        Rgba::new(from_byte(color.r), from_byte(color.g), from_byte(color.b), from_byte(color.a))
+    }
+}
+
+impl From<Rgba> for Rgba8 {
+    fn from(color: Rgba) -> Self {
+        Rgba8::new(to_byte(color.r), to_byte(color.g), to_byte(color.b), to_byte(color.a))
+    }
+}
+
+impl From<Rgb> for Rgba {
+    fn from(color: Rgb) -> Self {
+        color.with_alpha(1.0)
+    }
+}
+
+impl From<Rgb8> for Rgba8 {
+    fn from(color: Rgb8) -> Self {
+        color.with_alpha(255)
+    }
+}
+
+
+// Vectors and arrays
+
+impl From<Vector3> for Rgb {
+    fn from(v: Vector3) -> Self {
+        Rgb::new(v.x as f32, v.y as f32, v.z as f32)
+    }
+}
+
+impl From<Rgb> for Vector3 {
+    fn from(color: Rgb) -> Self {
+        Vector3::new(color.r as f64, color.g as f64, color.b as f64)
+    }
+}
+
+impl From<Vector4> for Rgba {
+    fn from(v: Vector4) -> Self {
+        Rgba::new(v.x as f32, v.y as f32, v.z as f32, v.w as f32)
+    }
+}
+
+impl From<Rgba> for Vector4 {
+    fn from(color: Rgba) -> Self {
+        Vector4::new(color.r as f64, color.g as f64, color.b as f64, color.a as f64)
+    }
+}
+
+impl From<[f32; 3]> for Rgb {
+    fn from(array: [f32; 3]) -> Self {
+        Rgb::new(array[0], array[1], array[2])
+    }
+}
+
+impl From<Rgb> for [f32; 3] {
+    fn from(color: Rgb) -> Self {
+        [color.r, color.g, color.b]
+    }
+}
+
+impl From<[f32; 4]> for Rgba {
+    fn from(array: [f32; 4]) -> Self {
+        Rgba::new(array[0], array[1], array[2], array[3])
+    }
+}
+
+impl From<Rgba> for [f32; 4] {
+    fn from(color: Rgba) -> Self {
+        [color.r, color.g, color.b, color.a]
+    }
+}
+
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseColorError::Length => write!(f, "unsupported number of hex digits"),
+            ParseColorError::Digit => write!(f, "invalid hexadecimal digit"),
+        }
+    }
+}
+
+impl Error for ParseColorError {}
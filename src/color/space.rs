@@ -0,0 +1,188 @@
+use super::Rgb;
+
+/// Hue, saturation and value, with the hue in degrees in `[0, 360)`
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug, Default)]
+pub struct Hsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+}
+
+
+/// Hue, saturation and lightness, with the hue in degrees in `[0, 360)`
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug, Default)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+
+/// The Oklab perceptual color space, for perceptually even gradients and color differences
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug, Default)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+
+/// Hue in degrees and chroma, the difference of the largest and smallest channel
+fn hue_chroma(color: Rgb) -> (f32, f32, f32) {
+    let max = color.r.max(color.g).max(color.b);
+    let min = color.r.min(color.g).min(color.b);
+    let chroma = max - min;
+
+    let hue = if chroma == 0.0 {
+        0.0
+    } else if max == color.r {
+        ((color.g - color.b) / chroma).rem_euclid(6.0)
+    } else if max == color.g {
+        (color.b - color.r) / chroma + 2.0
+    } else {
+        (color.r - color.g) / chroma + 4.0
+    };
+
+    (hue * 60.0, chroma, max)
+}
+
+/// The color with a hue and chroma, before adding the smallest channel
+fn from_hue_chroma(hue: f32, chroma: f32) -> Rgb {
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+
+    match sector as u32 {
+        0 => Rgb::new(chroma, x, 0.0),
+        1 => Rgb::new(x, chroma, 0.0),
+        2 => Rgb::new(0.0, chroma, x),
+        3 => Rgb::new(0.0, x, chroma),
+        4 => Rgb::new(x, 0.0, chroma),
+        _ => Rgb::new(chroma, 0.0, x),
+    }
+}
+
+fn add(color: Rgb, amount: f32) -> Rgb {
+    Rgb::new(color.r + amount, color.g + amount, color.b + amount)
+}
+
+
+impl Hsv {
+    /// Create a new color
+    pub fn new(h: f32, s: f32, v: f32) -> Hsv {
+        Hsv { h, s, v }
+    }
+}
+
+
+impl Hsl {
+    /// Create a new color
+    pub fn new(h: f32, s: f32, l: f32) -> Hsl {
+        Hsl { h, s, l }
+    }
+}
+
+
+impl Oklab {
+    /// Create a new color
+    pub fn new(l: f32, a: f32, b: f32) -> Oklab {
+        Oklab { l, a, b }
+    }
+
+
+    /// Convert from a linear color
+    pub fn from_linear(color: Rgb) -> Oklab {
+        let (r, g, b) = (color.r as f64, color.g as f64, color.b as f64);
+
+        let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+        let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+        let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+
+        Oklab {
+            l: (0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s) as f32,
+            a: (1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s) as f32,
+            b: (0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s) as f32,
+        }
+    }
+
+    /// Convert to a linear color, which may be out of the `[0, 1]` range
+    pub fn to_linear(&self) -> Rgb {
+        let (big_l, a, b) = (self.l as f64, self.a as f64, self.b as f64);
+
+        let l = (big_l + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+        let m = (big_l - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+        let s = (big_l - 0.089_484_177_5 * a - 1.291_485_548_0 * b).powi(3);
+
+        Rgb::new(
+            (4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s) as f32,
+            (-1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s) as f32,
+            (-0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s) as f32,
+        )
+    }
+
+
+    /// Linear interpolation, giving perceptually even gradients
+    pub fn lerp(&self, other: Oklab, t: f32) -> Oklab {
+        Oklab {
+            l: self.l + (other.l - self.l) * t,
+            a: self.a + (other.a - self.a) * t,
+            b: self.b + (other.b - self.b) * t,
+        }
+    }
+
+    /// Euclidean distance, an approximation of the perceived difference
+    pub fn distance(&self, other: Oklab) -> f32 {
+        let (l, a, b) = (self.l - other.l, self.a - other.a, self.b - other.b);
+        (l * l + a * a + b * b).sqrt()
+    }
+}
+
+
+// CONVERSION
+
+// HSV and HSL are reparametrizations of RGB and keep its encoding, usually sRGB
+
+impl From<Rgb> for Hsv {
+    fn from(color: Rgb) -> Self {
+        let (h, chroma, max) = hue_chroma(color);
+        let s = if max == 0.0 { 0.0 } else { chroma / max };
+        Hsv { h, s, v: max }
+    }
+}
+
+impl From<Hsv> for Rgb {
+    fn from(color: Hsv) -> Self {
+        let chroma = color.v * color.s;
+        add(from_hue_chroma(color.h, chroma), color.v - chroma)
+    }
+}
+
+impl From<Rgb> for Hsl {
+    fn from(color: Rgb) -> Self {
+        let (h, chroma, max) = hue_chroma(color);
+        let l = max - chroma / 2.0;
+        let s = if l == 0.0 || l == 1.0 { 0.0 } else { chroma / (1.0 - (2.0 * l - 1.0).abs()) };
+        Hsl { h, s, l }
+    }
+}
+
+impl From<Hsl> for Rgb {
+    fn from(color: Hsl) -> Self {
+        let chroma = (1.0 - (2.0 * color.l - 1.0).abs()) * color.s;
+        add(from_hue_chroma(color.h, chroma), color.l - chroma / 2.0)
+    }
+}
+
+impl From<Hsv> for Hsl {
+    fn from(color: Hsv) -> Self {
+        Rgb::from(color).into()
+    }
+}
+
+impl From<Hsl> for Hsv {
+    fn from(color: Hsl) -> Self {
+        Rgb::from(color).into()
+    }
+}
@@ -0,0 +1,405 @@
+pub use self::morton::{Morton2, Morton3};
+pub use self::hilbert::{Hilbert2, Hilbert3};
+
+/// Shift signed coordinates by an offset into the unsigned range `[0, 2^bits)`
+fn to_unsigned(coordinates: &[i64], offset: &[i64], bits: u32, out: &mut [u64]) -> bool {
+    for i in 0..coordinates.len() {
+        let shifted = match coordinates[i].checked_add(offset[i]) {
+            Some(shifted) => shifted,
+            None => return false,
+        };
+
+        if shifted < 0 || shifted as u64 >= 1 << bits {
+            return false;
+        }
+
+        out[i] = shifted as u64;
+    }
+
+    true
+}
+
+mod morton {
+    use super::to_unsigned;
+    use linear::{Vector2i, Vector3i};
+
+    /// Spread the lower 32 bits so that there is a zero bit between each
+    fn spread2(value: u64) -> u64 {
+        let mut x = value & 0x0000_0000_ffff_ffff;
+        x = (x | x << 16) & 0x0000_ffff_0000_ffff;
+        x = (x | x << 8) & 0x00ff_00ff_00ff_00ff;
+        x = (x | x << 4) & 0x0f0f_0f0f_0f0f_0f0f;
+        x = (x | x << 2) & 0x3333_3333_3333_3333;
+        x = (x | x << 1) & 0x5555_5555_5555_5555;
+        x
+    }
+
+    /// Inverse of `spread2`
+    fn compact2(value: u64) -> u64 {
+        let mut x = value & 0x5555_5555_5555_5555;
+        x = (x | x >> 1) & 0x3333_3333_3333_3333;
+        x = (x | x >> 2) & 0x0f0f_0f0f_0f0f_0f0f;
+        x = (x | x >> 4) & 0x00ff_00ff_00ff_00ff;
+        x = (x | x >> 8) & 0x0000_ffff_0000_ffff;
+        x = (x | x >> 16) & 0x0000_0000_ffff_ffff;
+        x
+    }
+
+    /// Spread the lower 21 bits so that there are two zero bits between each
+    fn spread3(value: u64) -> u64 {
+        let mut x = value & 0x1f_ffff;
+        x = (x | x << 32) & 0x001f_0000_0000_ffff;
+        x = (x | x << 16) & 0x001f_0000_ff00_00ff;
+        x = (x | x << 8) & 0x100f_00f0_0f00_f00f;
+        x = (x | x << 4) & 0x10c3_0c30_c30c_30c3;
+        x = (x | x << 2) & 0x1249_2492_4924_9249;
+        x
+    }
+
+    /// Inverse of `spread3`
+    fn compact3(value: u64) -> u64 {
+        let mut x = value & 0x1249_2492_4924_9249;
+        x = (x ^ (x >> 2)) & 0x10c3_0c30_c30c_30c3;
+        x = (x ^ (x >> 4)) & 0x100f_00f0_0f00_f00f;
+        x = (x ^ (x >> 8)) & 0x001f_0000_ff00_00ff;
+        x = (x ^ (x >> 16)) & 0x001f_0000_0000_ffff;
+        x = (x ^ (x >> 32)) & 0x1f_ffff;
+        x
+    }
+
+
+    /// Morton (Z-order) encoding of 2D coordinates, 32 bits per axis.
+    ///
+    /// Coordinates are shifted by `offset` before encoding and must then lie in `[0, 2^32)`.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    #[derive(Debug)]
+    pub struct Morton2 {
+        pub offset: Vector2i,
+    }
+
+
+    /// Morton (Z-order) encoding of 3D coordinates, 21 bits per axis.
+    ///
+    /// Coordinates are shifted by `offset` before encoding and must then lie in `[0, 2^21)`.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    #[derive(Debug)]
+    pub struct Morton3 {
+        pub offset: Vector3i,
+    }
+
+
+    impl Morton2 {
+        /// Bits per axis
+        pub const BITS: u32 = 32;
+
+
+        /// Create a new encoding with an offset
+        pub fn new(offset: Vector2i) -> Morton2 {
+            Morton2 { offset }
+        }
+
+        /// An encoding covering `[-2^31, 2^31)` on each axis
+        pub fn centered() -> Morton2 {
+            let half = 1 << (Morton2::BITS - 1);
+            Morton2::new(Vector2i::new(half, half))
+        }
+
+
+        /// Encode a coordinate, if it is in range
+        pub fn encode(&self, point: Vector2i) -> Option<u64> {
+            let mut axes = [0; 2];
+            if !to_unsigned(&[point.x, point.y], &[self.offset.x, self.offset.y], Morton2::BITS, &mut axes) {
+                return None;
+            }
+
+            Some(spread2(axes[0]) | spread2(axes[1]) << 1)
+        }
+
+        /// Decode a coordinate, if removing the offset does not overflow
+        pub fn decode(&self, code: u64) -> Option<Vector2i> {
+            Some(Vector2i::new(
+                (compact2(code) as i64).checked_sub(self.offset.x)?,
+                (compact2(code >> 1) as i64).checked_sub(self.offset.y)?,
+            ))
+        }
+    }
+
+
+    impl Morton3 {
+        /// Bits per axis
+        pub const BITS: u32 = 21;
+
+
+        /// Create a new encoding with an offset
+        pub fn new(offset: Vector3i) -> Morton3 {
+            Morton3 { offset }
+        }
+
+        /// An encoding covering `[-2^20, 2^20)` on each axis
+        pub fn centered() -> Morton3 {
+            let half = 1 << (Morton3::BITS - 1);
+            Morton3::new(Vector3i::new(half, half, half))
+        }
+
+
+        /// Encode a coordinate, if it is in range
+        pub fn encode(&self, point: Vector3i) -> Option<u64> {
+            let mut axes = [0; 3];
+            let offset = [self.offset.x, self.offset.y, self.offset.z];
+            if !to_unsigned(&[point.x, point.y, point.z], &offset, Morton3::BITS, &mut axes) {
+                return None;
+            }
+
+            Some(spread3(axes[0]) | spread3(axes[1]) << 1 | spread3(axes[2]) << 2)
+        }
+
+        /// Decode a coordinate, if removing the offset does not overflow. The most significant
+        /// bit of `code` is ignored.
+        pub fn decode(&self, code: u64) -> Option<Vector3i> {
+            Some(Vector3i::new(
+                (compact3(code) as i64).checked_sub(self.offset.x)?,
+                (compact3(code >> 1) as i64).checked_sub(self.offset.y)?,
+                (compact3(code >> 2) as i64).checked_sub(self.offset.z)?,
+            ))
+        }
+    }
+
+
+    impl Default for Morton2 {
+        fn default() -> Self {
+            Morton2::new(Vector2i::new(0, 0))
+        }
+    }
+
+    impl Default for Morton3 {
+        fn default() -> Self {
+            Morton3::new(Vector3i::new(0, 0, 0))
+        }
+    }
+}
+
+mod hilbert {
+    use super::to_unsigned;
+    use linear::{Vector2i, Vector3i};
+
+    // Based on John Skilling, "Programming the Hilbert curve" (2004), which works on the
+    // "transposed" index: axis `i` holds every n:th bit of the index, starting at bit `i`
+    // from the top.
+
+    /// Convert coordinates in place into the transposed Hilbert index
+    fn axes_to_transpose(axes: &mut [u64], bits: u32) {
+        let n = axes.len();
+        let m = 1u64 << (bits - 1);
+
+        // Inverse undo
+        let mut q = m;
+        while q > 1 {
+            let p = q - 1;
+            for i in 0..n {
+                if axes[i] & q != 0 {
+                    axes[0] ^= p;
+                } else {
+                    let t = (axes[0] ^ axes[i]) & p;
+                    axes[0] ^= t;
+                    axes[i] ^= t;
+                }
+            }
+            q >>= 1;
+        }
+
+        // Gray encode
+        for i in 1..n {
+            axes[i] ^= axes[i - 1];
+        }
+
+        let mut t = 0;
+        let mut q = m;
+        while q > 1 {
+            if axes[n - 1] & q != 0 {
+                t ^= q - 1;
+            }
+            q >>= 1;
+        }
+
+        for axis in axes.iter_mut() {
+            *axis ^= t;
+        }
+    }
+
+    /// Convert a transposed Hilbert index in place into coordinates
+    fn transpose_to_axes(axes: &mut [u64], bits: u32) {
+        let n = axes.len();
+
+        // Gray decode
+        let t = axes[n - 1] >> 1;
+        for i in (1..n).rev() {
+            axes[i] ^= axes[i - 1];
+        }
+        axes[0] ^= t;
+
+        // Undo excess work
+        let mut q = 2u64;
+        while q != 1 << bits {
+            let p = q - 1;
+            for i in (0..n).rev() {
+                if axes[i] & q != 0 {
+                    axes[0] ^= p;
+                } else {
+                    let t = (axes[0] ^ axes[i]) & p;
+                    axes[0] ^= t;
+                    axes[i] ^= t;
+                }
+            }
+            q <<= 1;
+        }
+    }
+
+    /// Interleave the transposed index into a single integer, first axis most significant
+    fn interleave(axes: &[u64], bits: u32) -> u64 {
+        let mut index = 0;
+        for bit in (0..bits).rev() {
+            for axis in axes {
+                index = index << 1 | (axis >> bit) & 1;
+            }
+        }
+        index
+    }
+
+    /// Inverse of `interleave`
+    fn deinterleave(index: u64, bits: u32, axes: &mut [u64]) {
+        let n = axes.len() as u32;
+        for (i, axis) in axes.iter_mut().enumerate() {
+            *axis = 0;
+            for bit in 0..bits {
+                let position = bit * n + (n - 1 - i as u32);
+                *axis |= (index >> position & 1) << bit;
+            }
+        }
+    }
+
+
+    /// Hilbert curve encoding of 2D coordinates with `order` bits per axis (at most 32).
+    ///
+    /// Coordinates are shifted by `offset` before encoding and must then lie in `[0, 2^order)`.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    #[derive(Debug)]
+    pub struct Hilbert2 {
+        order: u32,
+        pub offset: Vector2i,
+    }
+
+
+    /// Hilbert curve encoding of 3D coordinates with `order` bits per axis (at most 21).
+    ///
+    /// Coordinates are shifted by `offset` before encoding and must then lie in `[0, 2^order)`.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    #[derive(Debug)]
+    pub struct Hilbert3 {
+        order: u32,
+        pub offset: Vector3i,
+    }
+
+
+    impl Hilbert2 {
+        /// Maximum bits per axis
+        pub const MAX_ORDER: u32 = 32;
+
+
+        /// Create a new encoding. Panics if `order` is not within `1..=32`.
+        pub fn new(order: u32, offset: Vector2i) -> Hilbert2 {
+            assert!((1..=Hilbert2::MAX_ORDER).contains(&order), "Hilbert2 order must be within 1..=32");
+            Hilbert2 { order, offset }
+        }
+
+        /// An encoding covering `[-2^(order-1), 2^(order-1))` on each axis. Panics if `order` is
+        /// not within `1..=32`.
+        pub fn centered(order: u32) -> Hilbert2 {
+            let half = 1 << (order.max(1) - 1);
+            Hilbert2::new(order, Vector2i::new(half, half))
+        }
+
+
+        /// Bits per axis
+        pub fn order(&self) -> u32 {
+            self.order
+        }
+
+
+        /// Encode a coordinate, if it is in range
+        pub fn encode(&self, point: Vector2i) -> Option<u64> {
+            let mut axes = [0; 2];
+            if !to_unsigned(&[point.x, point.y], &[self.offset.x, self.offset.y], self.order, &mut axes) {
+                return None;
+            }
+
+            axes_to_transpose(&mut axes, self.order);
+            Some(interleave(&axes, self.order))
+        }
+
+        /// Decode a coordinate, if removing the offset does not overflow. Bits of `index` above
+        /// `2 * order` are ignored.
+        pub fn decode(&self, index: u64) -> Option<Vector2i> {
+            let mut axes = [0; 2];
+            deinterleave(index, self.order, &mut axes);
+            transpose_to_axes(&mut axes, self.order);
+
+            Some(Vector2i::new(
+                (axes[0] as i64).checked_sub(self.offset.x)?,
+                (axes[1] as i64).checked_sub(self.offset.y)?,
+            ))
+        }
+    }
+
+
+    impl Hilbert3 {
+        /// Maximum bits per axis
+        pub const MAX_ORDER: u32 = 21;
+
+
+        /// Create a new encoding. Panics if `order` is not within `1..=21`.
+        pub fn new(order: u32, offset: Vector3i) -> Hilbert3 {
+            assert!((1..=Hilbert3::MAX_ORDER).contains(&order), "Hilbert3 order must be within 1..=21");
+            Hilbert3 { order, offset }
+        }
+
+        /// An encoding covering `[-2^(order-1), 2^(order-1))` on each axis. Panics if `order` is
+        /// not within `1..=21`.
+        pub fn centered(order: u32) -> Hilbert3 {
+            let half = 1 << (order.max(1) - 1);
+            Hilbert3::new(order, Vector3i::new(half, half, half))
+        }
+
+
+        /// Bits per axis
+        pub fn order(&self) -> u32 {
+            self.order
+        }
+
+
+        /// Encode a coordinate, if it is in range
+        pub fn encode(&self, point: Vector3i) -> Option<u64> {
+            let mut axes = [0; 3];
+            let offset = [self.offset.x, self.offset.y, self.offset.z];
+            if !to_unsigned(&[point.x, point.y, point.z], &offset, self.order, &mut axes) {
+                return None;
+            }
+
+            axes_to_transpose(&mut axes, self.order);
+            Some(interleave(&axes, self.order))
+        }
+
+        /// Decode a coordinate, if removing the offset does not overflow. Bits of `index` above
+        /// `3 * order` are ignored.
+        pub fn decode(&self, index: u64) -> Option<Vector3i> {
+            let mut axes = [0; 3];
+            deinterleave(index, self.order, &mut axes);
+            transpose_to_axes(&mut axes, self.order);
+
+            Some(Vector3i::new(
+                (axes[0] as i64).checked_sub(self.offset.x)?,
+                (axes[1] as i64).checked_sub(self.offset.y)?,
+                (axes[2] as i64).checked_sub(self.offset.z)?,
+            ))
+        }
+    }
+}
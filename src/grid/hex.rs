@@ -0,0 +1,467 @@
+pub use self::coords::{Hex, CubeHex, FractionalHex};
+pub use self::layout::{HexLayout, HexOrientation, HexOffset};
+
+mod coords {
+    use std::ops::{
+        Add, AddAssign,
+        Sub, SubAssign,
+        Mul,
+        Neg,
+    };
+
+    /// The six axial directions, counter-clockwise starting at +q
+    const DIRECTIONS: [Hex; 6] = [
+        Hex { q: 1, r: 0 },
+        Hex { q: 1, r: -1 },
+        Hex { q: 0, r: -1 },
+        Hex { q: -1, r: 0 },
+        Hex { q: -1, r: 1 },
+        Hex { q: 0, r: 1 },
+    ];
+
+    /// The six diagonal directions, counter-clockwise starting between +q and -r
+    const DIAGONALS: [Hex; 6] = [
+        Hex { q: 2, r: -1 },
+        Hex { q: 1, r: -2 },
+        Hex { q: -1, r: -1 },
+        Hex { q: -2, r: 1 },
+        Hex { q: -1, r: 2 },
+        Hex { q: 1, r: 1 },
+    ];
+
+
+    /// A hexagon in axial coordinates. The implied third cube coordinate is `s = -q - r`.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    #[derive(Debug)]
+    pub struct Hex {
+        pub q: i64,
+        pub r: i64,
+    }
+
+
+    /// A hexagon in cube coordinates, where `q + r + s == 0`
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    #[derive(Debug)]
+    pub struct CubeHex {
+        pub q: i64,
+        pub r: i64,
+        pub s: i64,
+    }
+
+
+    /// A position in fractional cube coordinates, eg. a point between hexagon centers
+    #[derive(Copy, Clone, PartialEq)]
+    #[derive(Debug)]
+    pub struct FractionalHex {
+        pub q: f64,
+        pub r: f64,
+        pub s: f64,
+    }
+
+
+    impl Hex {
+        /// Create a new hexagon
+        pub fn new(q: i64, r: i64) -> Hex {
+            Hex { q, r }
+        }
+
+
+        /// The third cube coordinate
+        pub fn s(&self) -> i64 {
+            -self.q - self.r
+        }
+
+
+        /// The unit offset in one of the six directions, wrapping modulo 6
+        pub fn direction(direction: usize) -> Hex {
+            DIRECTIONS[direction % 6]
+        }
+
+        /// The neighbor in one of the six directions, wrapping modulo 6
+        pub fn neighbor(&self, direction: usize) -> Hex {
+            *self + Hex::direction(direction)
+        }
+
+        /// All six neighbors, counter-clockwise starting at +q
+        pub fn neighbors(&self) -> [Hex; 6] {
+            let mut neighbors = DIRECTIONS;
+            for neighbor in neighbors.iter_mut() {
+                *neighbor += *self;
+            }
+            neighbors
+        }
+
+        /// The diagonal neighbor in one of the six directions, wrapping modulo 6
+        pub fn diagonal_neighbor(&self, direction: usize) -> Hex {
+            *self + DIAGONALS[direction % 6]
+        }
+
+
+        /// Number of steps from the origin
+        pub fn length(&self) -> i64 {
+            (self.q.abs() + self.r.abs() + self.s().abs()) / 2
+        }
+
+        /// Number of steps between hexagons
+        pub fn distance(&self, other: Hex) -> i64 {
+            (*self - other).length()
+        }
+
+
+        /// The hexagons exactly `radius` steps away, counter-clockwise
+        pub fn ring(&self, radius: u32) -> Vec<Hex> {
+            if radius == 0 {
+                return vec![*self];
+            }
+
+            let radius = radius as i64;
+            let mut ring = Vec::with_capacity(6 * radius as usize);
+            let mut hex = *self + Hex::direction(4) * radius;
+            for direction in 0..6 {
+                for _ in 0..radius {
+                    ring.push(hex);
+                    hex = hex.neighbor(direction);
+                }
+            }
+
+            ring
+        }
+
+        /// The hexagons at most `radius` steps away, ordered ring by ring outwards
+        pub fn spiral(&self, radius: u32) -> Vec<Hex> {
+            let mut spiral = Vec::with_capacity(1 + 3 * radius as usize * (radius as usize + 1));
+            for ring in 0..radius + 1 {
+                spiral.extend(self.ring(ring));
+            }
+            spiral
+        }
+
+
+        /// The hexagons on a straight line to another hexagon, both inclusive
+        pub fn line_to(&self, other: Hex) -> Vec<Hex> {
+            let steps = self.distance(other);
+
+            // Nudge the endpoints so that the line never lies exactly on a hexagon edge
+            let start = FractionalHex::from(*self) + FractionalHex::new(1e-6, 2e-6);
+            let end = FractionalHex::from(other) + FractionalHex::new(1e-6, 2e-6);
+
+            (0..steps + 1)
+                .map(|step| {
+                    let t = if steps == 0 { 0.0 } else { step as f64 / steps as f64 };
+                    start.lerp(end, t).round()
+                })
+                .collect()
+        }
+
+
+        /// Rotate 60 degrees counter-clockwise around the origin
+        pub fn rotate_left(&self) -> Hex {
+            Hex { q: -self.s(), r: -self.q }
+        }
+
+        /// Rotate 60 degrees clockwise around the origin
+        pub fn rotate_right(&self) -> Hex {
+            Hex { q: -self.r, r: -self.s() }
+        }
+
+        /// Rotate by a number of 60 degree steps around another hexagon, positive steps
+        /// being counter-clockwise
+        pub fn rotate_around(&self, center: Hex, steps: i64) -> Hex {
+            let mut offset = *self - center;
+            for _ in 0..steps.rem_euclid(6) {
+                offset = offset.rotate_left();
+            }
+            center + offset
+        }
+
+
+        /// Reflect across the q axis, swapping r and s
+        pub fn reflect_q(&self) -> Hex {
+            Hex { q: self.q, r: self.s() }
+        }
+
+        /// Reflect across the r axis, swapping q and s
+        pub fn reflect_r(&self) -> Hex {
+            Hex { q: self.s(), r: self.r }
+        }
+
+        /// Reflect across the s axis, swapping q and r
+        pub fn reflect_s(&self) -> Hex {
+            Hex { q: self.r, r: self.q }
+        }
+    }
+
+
+    impl FractionalHex {
+        /// Create a new fractional position from axial coordinates
+        pub fn new(q: f64, r: f64) -> FractionalHex {
+            FractionalHex { q, r, s: -q - r }
+        }
+
+
+        /// Linear interpolation between two positions
+        pub fn lerp(&self, other: FractionalHex, t: f64) -> FractionalHex {
+            FractionalHex {
+                q: self.q + (other.q - self.q) * t,
+                r: self.r + (other.r - self.r) * t,
+                s: self.s + (other.s - self.s) * t,
+            }
+        }
+
+
+        /// The hexagon containing this position
+        pub fn round(&self) -> Hex {
+            let mut q = self.q.round();
+            let mut r = self.r.round();
+            let s = self.s.round();
+
+            let dq = (q - self.q).abs();
+            let dr = (r - self.r).abs();
+            let ds = (s - self.s).abs();
+
+            // Reset the coordinate with the largest rounding error so that q + r + s == 0
+            if dq > dr && dq > ds {
+                q = -r - s;
+            } else if dr > ds {
+                r = -q - s;
+            }
+
+            Hex { q: q as i64, r: r as i64 }
+        }
+    }
+
+
+    // ADDITION
+
+    impl Add<Hex> for Hex {
+        type Output = Hex;
+
+        fn add(self, rhs: Hex) -> Self::Output {
+            Hex {
+                q: self.q + rhs.q,
+                r: self.r + rhs.r,
+            }
+        }
+    }
+
+    impl AddAssign<Hex> for Hex {
+        fn add_assign(&mut self, rhs: Hex) {
+            self.q += rhs.q;
+            self.r += rhs.r;
+        }
+    }
+
+    impl Add<FractionalHex> for FractionalHex {
+        type Output = FractionalHex;
+
+        fn add(self, rhs: FractionalHex) -> Self::Output {
+            FractionalHex {
+                q: self.q + rhs.q,
+                r: self.r + rhs.r,
+                s: self.s + rhs.s,
+            }
+        }
+    }
+
+
+    // SUBTRACTION
+
+    impl Sub<Hex> for Hex {
+        type Output = Hex;
+
+        fn sub(self, rhs: Hex) -> Self::Output {
+            Hex {
+                q: self.q - rhs.q,
+                r: self.r - rhs.r,
+            }
+        }
+    }
+
+    impl SubAssign<Hex> for Hex {
+        fn sub_assign(&mut self, rhs: Hex) {
+            self.q -= rhs.q;
+            self.r -= rhs.r;
+        }
+    }
+
+
+    // MULTIPLICATION
+
+    impl Mul<i64> for Hex {
+        type Output = Hex;
+
+        fn mul(self, rhs: i64) -> Self::Output {
+            Hex {
+                q: self.q * rhs,
+                r: self.r * rhs,
+            }
+        }
+    }
+
+
+    // NEGATION
+
+    impl Neg for Hex {
+        type Output = Hex;
+
+        fn neg(self) -> Self::Output {
+            Hex { q: -self.q, r: -self.r }
+        }
+    }
+
+
+    // CONVERSION
+
+    impl From<CubeHex> for Hex {
+        fn from(cube: CubeHex) -> Self {
+            Hex { q: cube.q, r: cube.r }
+        }
+    }
+
+    impl From<Hex> for CubeHex {
+        fn from(hex: Hex) -> Self {
+            CubeHex { q: hex.q, r: hex.r, s: hex.s() }
+        }
+    }
+
+    impl From<Hex> for FractionalHex {
+        fn from(hex: Hex) -> Self {
+            FractionalHex {
+                q: hex.q as f64,
+                r: hex.r as f64,
+                s: hex.s() as f64,
+            }
+        }
+    }
+}
+
+mod layout {
+    use super::coords::{Hex, FractionalHex};
+    use linear::{Vector2, Vector2i};
+
+    const SQRT_3: f64 = 1.732_050_807_568_877_2;
+
+
+    /// Which way the hexagons point
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    #[derive(Debug)]
+    pub enum HexOrientation {
+        /// Hexagons have a corner at the top, rows are horizontal
+        Pointy,
+
+        /// Hexagons have an edge at the top, columns are vertical
+        Flat,
+    }
+
+
+    /// Offset (column, row) coordinate conventions for rectangular hex maps
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    #[derive(Debug)]
+    pub enum HexOffset {
+        /// Pointy hexagons, odd rows shoved right
+        OddR,
+
+        /// Pointy hexagons, even rows shoved right
+        EvenR,
+
+        /// Flat hexagons, odd columns shoved down
+        OddQ,
+
+        /// Flat hexagons, even columns shoved down
+        EvenQ,
+    }
+
+
+    /// Maps hexagons to and from pixel positions
+    #[derive(Copy, Clone, PartialEq)]
+    #[derive(Debug)]
+    pub struct HexLayout {
+        pub orientation: HexOrientation,
+
+        /// Distance from a hexagon's center to its corners along each axis
+        pub size: Vector2,
+
+        /// Pixel position of the center of the origin hexagon
+        pub origin: Vector2,
+    }
+
+
+    impl Hex {
+        /// Convert from offset coordinates, where `x` is the column and `y` the row
+        pub fn from_offset(offset: Vector2i, convention: HexOffset) -> Hex {
+            let Vector2i { x: col, y: row } = offset;
+            match convention {
+                HexOffset::OddR => Hex::new(col - (row - (row & 1)) / 2, row),
+                HexOffset::EvenR => Hex::new(col - (row + (row & 1)) / 2, row),
+                HexOffset::OddQ => Hex::new(col, row - (col - (col & 1)) / 2),
+                HexOffset::EvenQ => Hex::new(col, row - (col + (col & 1)) / 2),
+            }
+        }
+
+        /// Convert to offset coordinates, where `x` is the column and `y` the row
+        pub fn to_offset(&self, convention: HexOffset) -> Vector2i {
+            let Hex { q, r } = *self;
+            match convention {
+                HexOffset::OddR => Vector2i::new(q + (r - (r & 1)) / 2, r),
+                HexOffset::EvenR => Vector2i::new(q + (r + (r & 1)) / 2, r),
+                HexOffset::OddQ => Vector2i::new(q, r + (q - (q & 1)) / 2),
+                HexOffset::EvenQ => Vector2i::new(q, r + (q + (q & 1)) / 2),
+            }
+        }
+    }
+
+
+    impl HexLayout {
+        /// Create a new layout
+        pub fn new(orientation: HexOrientation, size: Vector2, origin: Vector2) -> HexLayout {
+            HexLayout { orientation, size, origin }
+        }
+
+
+        /// The pixel position of a hexagon's center
+        pub fn to_pixel(&self, hex: Hex) -> Vector2 {
+            self.fractional_to_pixel(hex.into())
+        }
+
+        /// The pixel position of a fractional hex position
+        pub fn fractional_to_pixel(&self, hex: FractionalHex) -> Vector2 {
+            let (x, y) = match self.orientation {
+                HexOrientation::Pointy => (SQRT_3 * hex.q + SQRT_3 / 2.0 * hex.r, 1.5 * hex.r),
+                HexOrientation::Flat => (1.5 * hex.q, SQRT_3 / 2.0 * hex.q + SQRT_3 * hex.r),
+            };
+
+            self.origin + Vector2::new(x, y) * self.size
+        }
+
+        /// The fractional hex position of a pixel
+        pub fn to_fractional(&self, pixel: Vector2) -> FractionalHex {
+            let Vector2 { x, y } = (pixel - self.origin) / self.size;
+            match self.orientation {
+                HexOrientation::Pointy => FractionalHex::new(SQRT_3 / 3.0 * x - y / 3.0, 2.0 / 3.0 * y),
+                HexOrientation::Flat => FractionalHex::new(2.0 / 3.0 * x, -x / 3.0 + SQRT_3 / 3.0 * y),
+            }
+        }
+
+        /// The hexagon containing a pixel
+        pub fn hex_at(&self, pixel: Vector2) -> Hex {
+            self.to_fractional(pixel).round()
+        }
+
+
+        /// The pixel positions of a hexagon's six corners
+        pub fn corners(&self, hex: Hex) -> [Vector2; 6] {
+            let center = self.to_pixel(hex);
+            let start_angle = match self.orientation {
+                HexOrientation::Pointy => 0.5,
+                HexOrientation::Flat => 0.0,
+            };
+
+            let mut corners = [center; 6];
+            for (i, corner) in corners.iter_mut().enumerate() {
+                let angle = ::std::f64::consts::PI / 3.0 * (i as f64 + start_angle);
+                *corner += Vector2::new(angle.cos(), angle.sin()) * self.size;
+            }
+            corners
+        }
+    }
+}
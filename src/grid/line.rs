@@ -0,0 +1,345 @@
+pub use self::line2i::{Line2i, SupercoverLine2i};
+pub use self::line3i::Line3i;
+pub use self::voxel_ray::{VoxelRay, VoxelHit};
+
+mod line2i {
+    use linear::Vector2i;
+
+    /// Iterates the cells of a Bresenham line between two points, both inclusive.
+    ///
+    /// Consecutive cells are 8-connected.
+    #[derive(Clone, Debug)]
+    pub struct Line2i {
+        current: Vector2i,
+        end: Vector2i,
+        dx: i64,
+        dy: i64,
+        sx: i64,
+        sy: i64,
+        error: i64,
+        done: bool,
+    }
+
+
+    impl Line2i {
+        /// Create a new line from `start` to `end`
+        pub fn new(start: Vector2i, end: Vector2i) -> Line2i {
+            let dx = (end.x - start.x).abs();
+            let dy = -(end.y - start.y).abs();
+
+            Line2i {
+                current: start,
+                end,
+                dx,
+                dy,
+                sx: (end.x - start.x).signum(),
+                sy: (end.y - start.y).signum(),
+                error: dx + dy,
+                done: false,
+            }
+        }
+    }
+
+
+    impl Iterator for Line2i {
+        type Item = Vector2i;
+
+        fn next(&mut self) -> Option<Vector2i> {
+            if self.done {
+                return None;
+            }
+
+            let point = self.current;
+            if point == self.end {
+                self.done = true;
+                return Some(point);
+            }
+
+            let e2 = 2 * self.error;
+            if e2 >= self.dy {
+                self.error += self.dy;
+                self.current.x += self.sx;
+            }
+            if e2 <= self.dx {
+                self.error += self.dx;
+                self.current.y += self.sy;
+            }
+
+            Some(point)
+        }
+    }
+
+
+    /// Iterates every cell touched by the segment between the centers of two cells,
+    /// both inclusive.
+    ///
+    /// Where the segment passes exactly through a cell corner, both cells sharing
+    /// that corner are included before the diagonal cell.
+    #[derive(Clone, Debug)]
+    pub struct SupercoverLine2i {
+        current: Vector2i,
+        nx: i64,
+        ny: i64,
+        sx: i64,
+        sy: i64,
+        ix: i64,
+        iy: i64,
+
+        // Cells produced by the last step that have not been yielded yet
+        buffer: [Vector2i; 3],
+        head: usize,
+        len: usize,
+    }
+
+
+    impl SupercoverLine2i {
+        /// Create a new line from `start` to `end`
+        pub fn new(start: Vector2i, end: Vector2i) -> SupercoverLine2i {
+            SupercoverLine2i {
+                current: start,
+                nx: (end.x - start.x).abs(),
+                ny: (end.y - start.y).abs(),
+                sx: (end.x - start.x).signum(),
+                sy: (end.y - start.y).signum(),
+                ix: 0,
+                iy: 0,
+                buffer: [start; 3],
+                head: 0,
+                len: 1,
+            }
+        }
+
+
+        /// Advance to the next cell(s) along the line, returns false when the end is reached
+        fn step(&mut self) -> bool {
+            if self.ix >= self.nx && self.iy >= self.ny {
+                return false;
+            }
+
+            let Vector2i { x, y } = self.current;
+
+            // Compares the parameters (0.5 + ix) / nx and (0.5 + iy) / ny at which the
+            // segment crosses the next vertical and horizontal grid lines
+            let decision = (1 + 2 * self.ix) * self.ny - (1 + 2 * self.iy) * self.nx;
+
+            if decision == 0 {
+                self.current = Vector2i::new(x + self.sx, y + self.sy);
+                self.ix += 1;
+                self.iy += 1;
+
+                self.buffer = [
+                    Vector2i::new(x + self.sx, y),
+                    Vector2i::new(x, y + self.sy),
+                    self.current,
+                ];
+                self.len = 3;
+            } else {
+                if decision < 0 {
+                    self.current.x += self.sx;
+                    self.ix += 1;
+                } else {
+                    self.current.y += self.sy;
+                    self.iy += 1;
+                }
+
+                self.buffer[0] = self.current;
+                self.len = 1;
+            }
+
+            self.head = 0;
+            true
+        }
+    }
+
+
+    impl Iterator for SupercoverLine2i {
+        type Item = Vector2i;
+
+        fn next(&mut self) -> Option<Vector2i> {
+            if self.head >= self.len && !self.step() {
+                return None;
+            }
+
+            let point = self.buffer[self.head];
+            self.head += 1;
+            Some(point)
+        }
+    }
+}
+
+mod line3i {
+    use linear::Vector3i;
+
+    /// Iterates the cells of a 3D Bresenham line between two points, both inclusive.
+    ///
+    /// Consecutive cells are 26-connected.
+    #[derive(Clone, Debug)]
+    pub struct Line3i {
+        current: [i64; 3],
+        delta: [i64; 3],
+        step: [i64; 3],
+        error: [i64; 3],
+        steps: i64,
+        remaining: i64,
+    }
+
+
+    impl Line3i {
+        /// Create a new line from `start` to `end`
+        pub fn new(start: Vector3i, end: Vector3i) -> Line3i {
+            let difference: [i64; 3] = (end - start).into();
+            let delta = [difference[0].abs(), difference[1].abs(), difference[2].abs()];
+            let steps = delta[0].max(delta[1]).max(delta[2]);
+
+            Line3i {
+                current: start.into(),
+                delta,
+                step: [difference[0].signum(), difference[1].signum(), difference[2].signum()],
+                error: [steps / 2; 3],
+                steps,
+                remaining: steps + 1,
+            }
+        }
+    }
+
+
+    impl Iterator for Line3i {
+        type Item = Vector3i;
+
+        fn next(&mut self) -> Option<Vector3i> {
+            if self.remaining == 0 {
+                return None;
+            }
+
+            let point = self.current.into();
+            self.remaining -= 1;
+
+            // Every axis accumulates its own error against the driving (longest) axis
+            for axis in 0..3 {
+                self.error[axis] -= self.delta[axis];
+                if self.error[axis] < 0 {
+                    self.error[axis] += self.steps;
+                    self.current[axis] += self.step[axis];
+                }
+            }
+
+            Some(point)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.remaining as usize, Some(self.remaining as usize))
+        }
+    }
+
+    impl ExactSizeIterator for Line3i {}
+}
+
+mod voxel_ray {
+    use linear::{Vector3, Vector3i};
+
+    /// A voxel pierced by a `VoxelRay`
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub struct VoxelHit {
+        /// The voxel, covering the unit cube from `voxel` to `voxel + 1`
+        pub voxel: Vector3i,
+
+        /// Distance along the ray at which the voxel is entered
+        pub distance: f64,
+
+        /// Normal of the face through which the voxel was entered.
+        /// Zero for the voxel containing the ray's origin.
+        pub normal: Vector3i,
+    }
+
+
+    /// Walks the voxels pierced by a ray in order, using the Amanatides-Woo algorithm.
+    #[derive(Clone, Debug)]
+    pub struct VoxelRay {
+        voxel: [i64; 3],
+        step: [i64; 3],
+        t_max: [f64; 3],
+        t_delta: [f64; 3],
+        distance: f64,
+        normal: [i64; 3],
+        max_distance: f64,
+        done: bool,
+    }
+
+
+    impl VoxelRay {
+        /// Create a new ray walking at most `max_distance` from `origin` along `direction`.
+        ///
+        /// Distances are measured in world units regardless of the length of `direction`.
+        pub fn new(origin: Vector3, direction: Vector3, max_distance: f64) -> VoxelRay {
+            let length = direction.len();
+            let origin: [f64; 3] = origin.into();
+            let direction: [f64; 3] = if length > 0.0 {
+                (direction / length).into()
+            } else {
+                [0.0; 3]
+            };
+
+            let mut ray = VoxelRay {
+                voxel: [0; 3],
+                step: [0; 3],
+                t_max: [f64::INFINITY; 3],
+                t_delta: [f64::INFINITY; 3],
+                distance: 0.0,
+                normal: [0; 3],
+                max_distance,
+                done: false,
+            };
+
+            for axis in 0..3 {
+                let cell = origin[axis].floor();
+                ray.voxel[axis] = cell as i64;
+
+                if direction[axis] > 0.0 {
+                    ray.step[axis] = 1;
+                    ray.t_max[axis] = (cell + 1.0 - origin[axis]) / direction[axis];
+                    ray.t_delta[axis] = 1.0 / direction[axis];
+                } else if direction[axis] < 0.0 {
+                    ray.step[axis] = -1;
+                    ray.t_max[axis] = (cell - origin[axis]) / direction[axis];
+                    ray.t_delta[axis] = -1.0 / direction[axis];
+                }
+            }
+
+            ray
+        }
+    }
+
+
+    impl Iterator for VoxelRay {
+        type Item = VoxelHit;
+
+        fn next(&mut self) -> Option<VoxelHit> {
+            if self.done || self.distance > self.max_distance {
+                return None;
+            }
+
+            let hit = VoxelHit {
+                voxel: self.voxel.into(),
+                distance: self.distance,
+                normal: self.normal.into(),
+            };
+
+            let mut axis = 0;
+            if self.t_max[1] < self.t_max[axis] { axis = 1; }
+            if self.t_max[2] < self.t_max[axis] { axis = 2; }
+
+            if self.t_max[axis].is_infinite() {
+                self.done = true;
+            } else {
+                self.distance = self.t_max[axis];
+                self.voxel[axis] += self.step[axis];
+                self.t_max[axis] += self.t_delta[axis];
+
+                self.normal = [0; 3];
+                self.normal[axis] = -self.step[axis];
+            }
+
+            Some(hit)
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Algorithms operating on integer grids
+mod line;
+pub use self::line::{
+    Line2i,
+    SupercoverLine2i,
+    Line3i,
+    VoxelRay,
+    VoxelHit,
+};
+
+mod hex;
+pub use self::hex::{
+    Hex,
+    CubeHex,
+    FractionalHex,
+    HexLayout,
+    HexOrientation,
+    HexOffset,
+};
+
+mod curve;
+pub use self::curve::{
+    Morton2,
+    Morton3,
+    Hilbert2,
+    Hilbert3,
+};
@@ -0,0 +1,325 @@
+//! Parent-child hierarchies of transforms
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt;
+use linear::{Matrix4, Transform, DecomposeError};
+
+/// Handle to a node in a `TransformTree`.
+///
+/// Handles of removed nodes are never reused, even if their storage is.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+pub struct NodeId {
+    index: u32,
+    generation: u32,
+}
+
+
+/// Why a node could not be reparented
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+pub enum ReparentError {
+    /// The new parent is the node itself or one of its descendants
+    Cycle,
+
+    /// The world position was to be preserved, but the resulting local matrix could not be
+    /// represented as a `Transform`
+    Decompose(DecomposeError),
+}
+
+
+#[derive(Clone, Debug)]
+struct Node {
+    local: Transform,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+
+    // Cached world matrix, recomputed lazily when dirty
+    world: Cell<[[f64; 4]; 4]>,
+    dirty: Cell<bool>,
+}
+
+
+#[derive(Clone, Debug)]
+struct Slot {
+    generation: u32,
+    node: Option<Node>,
+}
+
+
+/// An arena of transforms arranged in a forest.
+///
+/// World matrices are computed lazily and cached. Changing a local transform or a parent
+/// marks the node and all of its descendants dirty.
+#[derive(Clone, Debug, Default)]
+pub struct TransformTree {
+    slots: Vec<Slot>,
+    free: Vec<u32>,
+    roots: Vec<NodeId>,
+    len: usize,
+}
+
+
+/// Iterates the nodes of a `TransformTree` such that parents come before their children
+pub struct Topological<'a> {
+    tree: &'a TransformTree,
+    stack: Vec<NodeId>,
+}
+
+
+impl TransformTree {
+    /// Create a new, empty tree
+    pub fn new() -> TransformTree {
+        TransformTree::default()
+    }
+
+
+    /// Number of nodes in the tree
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the tree has no nodes
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether the node exists in the tree
+    pub fn contains(&self, id: NodeId) -> bool {
+        self.slots.get(id.index as usize)
+            .is_some_and(|slot| slot.generation == id.generation && slot.node.is_some())
+    }
+
+
+    /// Add a node with a local transform, either as a root or as the last child of a parent
+    pub fn add(&mut self, local: Transform, parent: Option<NodeId>) -> NodeId {
+        if let Some(parent) = parent {
+            self.node(parent);
+        }
+
+        let node = Node {
+            local,
+            parent,
+            children: Vec::new(),
+            world: Cell::new(Matrix4::new().into()),
+            dirty: Cell::new(true),
+        };
+
+        let id = match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                slot.node = Some(node);
+                NodeId { index, generation: slot.generation }
+            }
+            None => {
+                self.slots.push(Slot { generation: 0, node: Some(node) });
+                NodeId { index: self.slots.len() as u32 - 1, generation: 0 }
+            }
+        };
+
+        self.siblings_mut(parent).push(id);
+        self.len += 1;
+        id
+    }
+
+    /// Remove a node along with all of its descendants
+    pub fn remove(&mut self, id: NodeId) {
+        let parent = self.node(id).parent;
+        self.siblings_mut(parent).retain(|sibling| *sibling != id);
+
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            let slot = &mut self.slots[id.index as usize];
+            let node = slot.node.take().expect("invalid NodeId");
+            slot.generation += 1;
+
+            stack.extend(node.children);
+            self.free.push(id.index);
+            self.len -= 1;
+        }
+    }
+
+
+    /// The local transform of a node, relative to its parent
+    pub fn local(&self, id: NodeId) -> Transform {
+        self.node(id).local
+    }
+
+    /// Set the local transform of a node
+    pub fn set_local(&mut self, id: NodeId, local: Transform) {
+        self.node_mut(id).local = local;
+        self.mark_dirty(id);
+    }
+
+
+    /// The parent of a node, if it is not a root
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).parent
+    }
+
+    /// The children of a node
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.node(id).children
+    }
+
+    /// The nodes without parents
+    pub fn roots(&self) -> &[NodeId] {
+        &self.roots
+    }
+
+
+    /// Move a node, along with its descendants, to a new parent or make it a root.
+    ///
+    /// If `keep_world` is set, the local transform is adjusted so that the node stays in
+    /// place, otherwise the local transform is kept and the node moves with its new parent.
+    pub fn set_parent(&mut self, id: NodeId, parent: Option<NodeId>, keep_world: bool) -> Result<(), ReparentError> {
+        let mut ancestor = parent;
+        while let Some(current) = ancestor {
+            if current == id {
+                return Err(ReparentError::Cycle);
+            }
+            ancestor = self.node(current).parent;
+        }
+
+        if keep_world {
+            let world = self.world_matrix(id);
+            let parent_world = match parent {
+                Some(parent) => self.world_matrix(parent),
+                None => Matrix4::new(),
+            };
+
+            let local = match parent_world.inverse() {
+                Some(inverse) => (inverse * world).decompose(),
+                None => Err(DecomposeError::Singular),
+            };
+
+            self.node_mut(id).local = local.map_err(ReparentError::Decompose)?;
+        }
+
+        let old_parent = self.node(id).parent;
+        self.siblings_mut(old_parent).retain(|sibling| *sibling != id);
+        self.siblings_mut(parent).push(id);
+        self.node_mut(id).parent = parent;
+
+        self.mark_dirty(id);
+        Ok(())
+    }
+
+
+    /// The world matrix of a node, combining the local transforms of all its ancestors
+    pub fn world_matrix(&self, id: NodeId) -> Matrix4 {
+        // Walk up to the first clean ancestor, then recompute back down, without recursing
+        let mut dirty = Vec::new();
+        let mut ancestor = Some(id);
+        while let Some(current) = ancestor {
+            let node = self.node(current);
+            if !node.dirty.get() {
+                break;
+            }
+            dirty.push(node);
+            ancestor = node.parent;
+        }
+
+        let mut parent_world: Option<Matrix4> = ancestor.map(|clean| self.node(clean).world.get().into());
+        for node in dirty.into_iter().rev() {
+            let local = node.local.to_matrix();
+            let world = match parent_world {
+                Some(parent_world) => parent_world * local,
+                None => local,
+            };
+
+            node.world.set(world.into());
+            node.dirty.set(false);
+            parent_world = Some(world);
+        }
+
+        self.node(id).world.get().into()
+    }
+
+    /// Recompute the world matrices of all dirty nodes
+    pub fn update(&self) {
+        let order: Vec<NodeId> = self.iter().collect();
+        for id in order {
+            // Parents are visited first, so at most one level is recomputed per node
+            self.world_matrix(id);
+        }
+    }
+
+
+    /// Iterate all nodes such that parents come before their children
+    pub fn iter(&self) -> Topological<'_> {
+        Topological {
+            tree: self,
+            stack: self.roots.iter().rev().cloned().collect(),
+        }
+    }
+
+
+    fn node(&self, id: NodeId) -> &Node {
+        self.slots.get(id.index as usize)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.node.as_ref())
+            .expect("invalid NodeId")
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> &mut Node {
+        self.slots.get_mut(id.index as usize)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.node.as_mut())
+            .expect("invalid NodeId")
+    }
+
+    /// The children of a parent, or the roots if there is none
+    fn siblings_mut(&mut self, parent: Option<NodeId>) -> &mut Vec<NodeId> {
+        match parent {
+            Some(parent) => &mut self.node_mut(parent).children,
+            None => &mut self.roots,
+        }
+    }
+
+    /// Mark a node and all of its descendants as dirty
+    fn mark_dirty(&self, id: NodeId) {
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            let node = self.node(id);
+
+            // Descendants of a dirty node are always dirty as well
+            if node.dirty.replace(true) {
+                continue;
+            }
+            stack.extend(node.children.iter().cloned());
+        }
+    }
+}
+
+
+impl<'a> Iterator for Topological<'a> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        let id = self.stack.pop()?;
+        self.stack.extend(self.tree.node(id).children.iter().rev().cloned());
+        Some(id)
+    }
+}
+
+impl<'a> IntoIterator for &'a TransformTree {
+    type Item = NodeId;
+    type IntoIter = Topological<'a>;
+
+    fn into_iter(self) -> Topological<'a> {
+        self.iter()
+    }
+}
+
+
+impl fmt::Display for ReparentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ReparentError::Cycle => write!(f, "node cannot become a descendant of itself"),
+            ReparentError::Decompose(ref error) => write!(f, "cannot preserve world transform: {}", error),
+        }
+    }
+}
+
+impl Error for ReparentError {}
@@ -0,0 +1,116 @@
+use cgmath;
+use linear::{Vector2, Vector3, Vector4, Vector2i, Vector3i, Vector4i, Matrix4};
+
+impl From<cgmath::Vector2<f64>> for Vector2 {
+    fn from(v: cgmath::Vector2<f64>) -> Self {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2> for cgmath::Vector2<f64> {
+    fn from(v: Vector2) -> Self {
+        cgmath::Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<cgmath::Vector3<f64>> for Vector3 {
+    fn from(v: cgmath::Vector3<f64>) -> Self {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3> for cgmath::Vector3<f64> {
+    fn from(v: Vector3) -> Self {
+        cgmath::Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<cgmath::Vector4<f64>> for Vector4 {
+    fn from(v: cgmath::Vector4<f64>) -> Self {
+        Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector4> for cgmath::Vector4<f64> {
+    fn from(v: Vector4) -> Self {
+        cgmath::Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+
+impl From<cgmath::Point2<f64>> for Vector2 {
+    fn from(p: cgmath::Point2<f64>) -> Self {
+        Vector2::new(p.x, p.y)
+    }
+}
+
+impl From<Vector2> for cgmath::Point2<f64> {
+    fn from(v: Vector2) -> Self {
+        cgmath::Point2::new(v.x, v.y)
+    }
+}
+
+impl From<cgmath::Point3<f64>> for Vector3 {
+    fn from(p: cgmath::Point3<f64>) -> Self {
+        Vector3::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<Vector3> for cgmath::Point3<f64> {
+    fn from(v: Vector3) -> Self {
+        cgmath::Point3::new(v.x, v.y, v.z)
+    }
+}
+
+
+impl From<cgmath::Vector2<i64>> for Vector2i {
+    fn from(v: cgmath::Vector2<i64>) -> Self {
+        Vector2i::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2i> for cgmath::Vector2<i64> {
+    fn from(v: Vector2i) -> Self {
+        cgmath::Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<cgmath::Vector3<i64>> for Vector3i {
+    fn from(v: cgmath::Vector3<i64>) -> Self {
+        Vector3i::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3i> for cgmath::Vector3<i64> {
+    fn from(v: Vector3i) -> Self {
+        cgmath::Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<cgmath::Vector4<i64>> for Vector4i {
+    fn from(v: cgmath::Vector4<i64>) -> Self {
+        Vector4i::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector4i> for cgmath::Vector4<i64> {
+    fn from(v: Vector4i) -> Self {
+        cgmath::Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+
+// cgmath matrices are column-major, like ours
+impl From<cgmath::Matrix4<f64>> for Matrix4 {
+    fn from(m: cgmath::Matrix4<f64>) -> Self {
+        let columns: [[f64; 4]; 4] = m.into();
+        Matrix4::from(columns)
+    }
+}
+
+impl From<Matrix4> for cgmath::Matrix4<f64> {
+    fn from(m: Matrix4) -> Self {
+        let columns: [[f64; 4]; 4] = m.into();
+        cgmath::Matrix4::from(columns)
+    }
+}
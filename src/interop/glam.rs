@@ -0,0 +1,138 @@
+use glam;
+use linear::{Vector2, Vector3, Vector4, Vector2i, Vector3i, Vector4i, Matrix4};
+
+impl From<glam::DVec2> for Vector2 {
+    fn from(v: glam::DVec2) -> Self {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2> for glam::DVec2 {
+    fn from(v: Vector2) -> Self {
+        glam::DVec2::new(v.x, v.y)
+    }
+}
+
+impl From<glam::DVec3> for Vector3 {
+    fn from(v: glam::DVec3) -> Self {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3> for glam::DVec3 {
+    fn from(v: Vector3) -> Self {
+        glam::DVec3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<glam::DVec4> for Vector4 {
+    fn from(v: glam::DVec4) -> Self {
+        Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector4> for glam::DVec4 {
+    fn from(v: Vector4) -> Self {
+        glam::DVec4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+
+impl From<glam::Vec2> for Vector2 {
+    fn from(v: glam::Vec2) -> Self {
+        Vector2::new(v.x as f64, v.y as f64)
+    }
+}
+
+impl From<Vector2> for glam::Vec2 {
+    fn from(v: Vector2) -> Self {
+        glam::Vec2::new(v.x as f32, v.y as f32)
+    }
+}
+
+impl From<glam::Vec3> for Vector3 {
+    fn from(v: glam::Vec3) -> Self {
+        Vector3::new(v.x as f64, v.y as f64, v.z as f64)
+    }
+}
+
+impl From<Vector3> for glam::Vec3 {
+    fn from(v: Vector3) -> Self {
+        glam::Vec3::new(v.x as f32, v.y as f32, v.z as f32)
+    }
+}
+
+impl From<glam::Vec4> for Vector4 {
+    fn from(v: glam::Vec4) -> Self {
+        Vector4::new(v.x as f64, v.y as f64, v.z as f64, v.w as f64)
+    }
+}
+
+impl From<Vector4> for glam::Vec4 {
+    fn from(v: Vector4) -> Self {
+        glam::Vec4::new(v.x as f32, v.y as f32, v.z as f32, v.w as f32)
+    }
+}
+
+
+impl From<glam::I64Vec2> for Vector2i {
+    fn from(v: glam::I64Vec2) -> Self {
+        Vector2i::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2i> for glam::I64Vec2 {
+    fn from(v: Vector2i) -> Self {
+        glam::I64Vec2::new(v.x, v.y)
+    }
+}
+
+impl From<glam::I64Vec3> for Vector3i {
+    fn from(v: glam::I64Vec3) -> Self {
+        Vector3i::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3i> for glam::I64Vec3 {
+    fn from(v: Vector3i) -> Self {
+        glam::I64Vec3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<glam::I64Vec4> for Vector4i {
+    fn from(v: glam::I64Vec4) -> Self {
+        Vector4i::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector4i> for glam::I64Vec4 {
+    fn from(v: Vector4i) -> Self {
+        glam::I64Vec4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+
+// glam matrices are column-major, like ours
+impl From<glam::DMat4> for Matrix4 {
+    fn from(m: glam::DMat4) -> Self {
+        Matrix4::from(m.to_cols_array_2d())
+    }
+}
+
+impl From<Matrix4> for glam::DMat4 {
+    fn from(m: Matrix4) -> Self {
+        glam::DMat4::from_cols_array_2d(&m.into())
+    }
+}
+
+impl From<glam::Mat4> for Matrix4 {
+    fn from(m: glam::Mat4) -> Self {
+        Matrix4::from(m.as_dmat4())
+    }
+}
+
+impl From<Matrix4> for glam::Mat4 {
+    fn from(m: Matrix4) -> Self {
+        glam::Mat4::from_cols_array_2d(&m.into())
+    }
+}
@@ -0,0 +1,106 @@
+use mint;
+use linear::{Vector2, Vector3, Vector4, Vector2i, Vector3i, Vector4i, Matrix4};
+
+impl From<mint::Vector2<f64>> for Vector2 {
+    fn from(v: mint::Vector2<f64>) -> Self {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2> for mint::Vector2<f64> {
+    fn from(v: Vector2) -> Self {
+        mint::Vector2 { x: v.x, y: v.y }
+    }
+}
+
+impl From<mint::Vector3<f64>> for Vector3 {
+    fn from(v: mint::Vector3<f64>) -> Self {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3> for mint::Vector3<f64> {
+    fn from(v: Vector3) -> Self {
+        mint::Vector3 { x: v.x, y: v.y, z: v.z }
+    }
+}
+
+impl From<mint::Vector4<f64>> for Vector4 {
+    fn from(v: mint::Vector4<f64>) -> Self {
+        Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector4> for mint::Vector4<f64> {
+    fn from(v: Vector4) -> Self {
+        mint::Vector4 { x: v.x, y: v.y, z: v.z, w: v.w }
+    }
+}
+
+
+impl From<mint::Vector2<i64>> for Vector2i {
+    fn from(v: mint::Vector2<i64>) -> Self {
+        Vector2i::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2i> for mint::Vector2<i64> {
+    fn from(v: Vector2i) -> Self {
+        mint::Vector2 { x: v.x, y: v.y }
+    }
+}
+
+impl From<mint::Vector3<i64>> for Vector3i {
+    fn from(v: mint::Vector3<i64>) -> Self {
+        Vector3i::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3i> for mint::Vector3<i64> {
+    fn from(v: Vector3i) -> Self {
+        mint::Vector3 { x: v.x, y: v.y, z: v.z }
+    }
+}
+
+impl From<mint::Vector4<i64>> for Vector4i {
+    fn from(v: mint::Vector4<i64>) -> Self {
+        Vector4i::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector4i> for mint::Vector4<i64> {
+    fn from(v: Vector4i) -> Self {
+        mint::Vector4 { x: v.x, y: v.y, z: v.z, w: v.w }
+    }
+}
+
+
+// Both arrays of arrays are columns
+impl From<mint::ColumnMatrix4<f64>> for Matrix4 {
+    fn from(m: mint::ColumnMatrix4<f64>) -> Self {
+        let columns: [[f64; 4]; 4] = m.into();
+        Matrix4::from(columns)
+    }
+}
+
+impl From<Matrix4> for mint::ColumnMatrix4<f64> {
+    fn from(m: Matrix4) -> Self {
+        let columns: [[f64; 4]; 4] = m.into();
+        mint::ColumnMatrix4::from(columns)
+    }
+}
+
+// The arrays of a row matrix are rows, so the columns are its transpose
+impl From<mint::RowMatrix4<f64>> for Matrix4 {
+    fn from(m: mint::RowMatrix4<f64>) -> Self {
+        let rows: [[f64; 4]; 4] = m.into();
+        Matrix4::from(rows).transpose()
+    }
+}
+
+impl From<Matrix4> for mint::RowMatrix4<f64> {
+    fn from(m: Matrix4) -> Self {
+        let rows: [[f64; 4]; 4] = m.transpose().into();
+        mint::RowMatrix4::from(rows)
+    }
+}
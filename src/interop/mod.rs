@@ -0,0 +1,17 @@
+//! Conversions to and from the types of other math crates, each behind a cargo feature of the
+//! same name.
+//!
+//! All four crates store matrices column-major like `Matrix4`, except for mint's `RowMatrix4`
+//! which is transposed on conversion.
+
+#[cfg(feature = "mint")]
+mod mint;
+
+#[cfg(feature = "glam")]
+mod glam;
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+#[cfg(feature = "cgmath")]
+mod cgmath;
@@ -0,0 +1,116 @@
+use nalgebra;
+use linear::{Vector2, Vector3, Vector4, Vector2i, Vector3i, Vector4i, Matrix4};
+
+impl From<nalgebra::Vector2<f64>> for Vector2 {
+    fn from(v: nalgebra::Vector2<f64>) -> Self {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2> for nalgebra::Vector2<f64> {
+    fn from(v: Vector2) -> Self {
+        nalgebra::Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<nalgebra::Vector3<f64>> for Vector3 {
+    fn from(v: nalgebra::Vector3<f64>) -> Self {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3> for nalgebra::Vector3<f64> {
+    fn from(v: Vector3) -> Self {
+        nalgebra::Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<nalgebra::Vector4<f64>> for Vector4 {
+    fn from(v: nalgebra::Vector4<f64>) -> Self {
+        Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector4> for nalgebra::Vector4<f64> {
+    fn from(v: Vector4) -> Self {
+        nalgebra::Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+
+impl From<nalgebra::Point2<f64>> for Vector2 {
+    fn from(p: nalgebra::Point2<f64>) -> Self {
+        Vector2::new(p.x, p.y)
+    }
+}
+
+impl From<Vector2> for nalgebra::Point2<f64> {
+    fn from(v: Vector2) -> Self {
+        nalgebra::Point2::new(v.x, v.y)
+    }
+}
+
+impl From<nalgebra::Point3<f64>> for Vector3 {
+    fn from(p: nalgebra::Point3<f64>) -> Self {
+        Vector3::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<Vector3> for nalgebra::Point3<f64> {
+    fn from(v: Vector3) -> Self {
+        nalgebra::Point3::new(v.x, v.y, v.z)
+    }
+}
+
+
+impl From<nalgebra::Vector2<i64>> for Vector2i {
+    fn from(v: nalgebra::Vector2<i64>) -> Self {
+        Vector2i::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2i> for nalgebra::Vector2<i64> {
+    fn from(v: Vector2i) -> Self {
+        nalgebra::Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<nalgebra::Vector3<i64>> for Vector3i {
+    fn from(v: nalgebra::Vector3<i64>) -> Self {
+        Vector3i::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3i> for nalgebra::Vector3<i64> {
+    fn from(v: Vector3i) -> Self {
+        nalgebra::Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<nalgebra::Vector4<i64>> for Vector4i {
+    fn from(v: nalgebra::Vector4<i64>) -> Self {
+        Vector4i::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector4i> for nalgebra::Vector4<i64> {
+    fn from(v: Vector4i) -> Self {
+        nalgebra::Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+
+// nalgebra converts arrays of arrays as columns, like ours
+impl From<nalgebra::Matrix4<f64>> for Matrix4 {
+    fn from(m: nalgebra::Matrix4<f64>) -> Self {
+        let columns: [[f64; 4]; 4] = m.into();
+        Matrix4::from(columns)
+    }
+}
+
+impl From<Matrix4> for nalgebra::Matrix4<f64> {
+    fn from(m: Matrix4) -> Self {
+        let columns: [[f64; 4]; 4] = m.into();
+        nalgebra::Matrix4::from(columns)
+    }
+}
@@ -1,14 +1,46 @@
 extern crate vecmath;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "mint")]
+extern crate mint;
+#[cfg(feature = "glam")]
+extern crate glam;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "cgmath")]
+extern crate cgmath;
 
 mod linear;
 pub use linear::*;
 
+mod grid;
+pub use grid::*;
+
+mod hierarchy;
+pub use hierarchy::*;
+
+mod color;
+pub use color::*;
+
+pub mod noise;
+
+pub mod sequence;
+
+#[cfg(feature = "rand")]
+pub mod random;
+
+mod interop;
+
 #[cfg(test)]
 mod tests {
     use linear::*;
+    use grid::*;
+    use hierarchy::*;
+    use color::*;
 
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn vec2_eq() {
         assert_eq!(
             Vector2 { x: 2.0, y: 3.14 },
@@ -88,6 +120,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(unused_variables)]
     fn matrix_translate() {
         let a = Vector4::new(0.0, 0.0, 0.0, 1.0);
         let b = Vector3::new(1.0, 2.0, 3.0);
@@ -112,4 +145,1359 @@ mod tests {
         println!("{:?}", result);
         assert_eq!(result, a * Vector4::new(1.5, 2.0, 3.0, 1.0))
     }
+
+
+    #[test]
+    fn line2i_bresenham() {
+        let cells: Vec<_> = Line2i::new(Vector2i::new(0, 0), Vector2i::new(5, 2)).collect();
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[0], Vector2i::new(0, 0));
+        assert_eq!(cells[5], Vector2i::new(5, 2));
+
+        for pair in cells.windows(2) {
+            let d = pair[1] - pair[0];
+            assert!(d.x.abs() <= 1 && d.y.abs() <= 1);
+        }
+
+        let single: Vec<_> = Line2i::new(Vector2i::new(3, -1), Vector2i::new(3, -1)).collect();
+        assert_eq!(single, vec![Vector2i::new(3, -1)]);
+    }
+
+    #[test]
+    fn line2i_supercover() {
+        let cells: Vec<_> = SupercoverLine2i::new(Vector2i::new(0, 0), Vector2i::new(4, 1)).collect();
+        assert_eq!(cells, vec![
+            Vector2i::new(0, 0), Vector2i::new(1, 0), Vector2i::new(2, 0),
+            Vector2i::new(2, 1), Vector2i::new(3, 1), Vector2i::new(4, 1),
+        ]);
+
+        // Passing exactly through corners includes both neighbouring cells
+        let diagonal: Vec<_> = SupercoverLine2i::new(Vector2i::new(0, 0), Vector2i::new(-1, 1)).collect();
+        assert_eq!(diagonal, vec![
+            Vector2i::new(0, 0), Vector2i::new(-1, 0), Vector2i::new(0, 1), Vector2i::new(-1, 1),
+        ]);
+    }
+
+    #[test]
+    fn line3i_bresenham() {
+        let line = Line3i::new(Vector3i::new(0, 0, 0), Vector3i::new(4, -2, 1));
+        assert_eq!(line.len(), 5);
+
+        let cells: Vec<_> = line.collect();
+        assert_eq!(cells[0], Vector3i::new(0, 0, 0));
+        assert_eq!(cells[4], Vector3i::new(4, -2, 1));
+        for pair in cells.windows(2) {
+            let d = pair[1] - pair[0];
+            assert!(d.x == 1 && d.y.abs() <= 1 && d.z.abs() <= 1);
+        }
+    }
+
+    #[test]
+    fn voxel_ray_traversal() {
+        let hits: Vec<_> = VoxelRay::new(
+            Vector3::new(0.5, 0.5, 0.5),
+            Vector3::new(2.0, 0.0, 0.0),
+            2.0
+        ).collect();
+
+        assert_eq!(hits.len(), 3);
+        assert_eq!(hits[0].voxel, Vector3i::new(0, 0, 0));
+        assert_eq!(hits[0].normal, Vector3i::new(0, 0, 0));
+        assert_eq!(hits[1].voxel, Vector3i::new(1, 0, 0));
+        assert_eq!(hits[1].distance, 0.5);
+        assert_eq!(hits[1].normal, Vector3i::new(-1, 0, 0));
+        assert_eq!(hits[2].distance, 1.5);
+
+        let down: Vec<_> = VoxelRay::new(
+            Vector3::new(0.25, 0.5, 0.5),
+            Vector3::new(-1.0, -1.0, 0.0),
+            1.0
+        ).map(|hit| (hit.voxel, hit.normal)).collect();
+
+        assert_eq!(down, vec![
+            (Vector3i::new(0, 0, 0), Vector3i::new(0, 0, 0)),
+            (Vector3i::new(-1, 0, 0), Vector3i::new(1, 0, 0)),
+            (Vector3i::new(-1, -1, 0), Vector3i::new(0, 1, 0)),
+        ]);
+    }
+
+
+    #[test]
+    fn hex_neighbors_and_distance() {
+        let hex = Hex::new(2, -1);
+        assert_eq!(CubeHex::from(hex), CubeHex { q: 2, r: -1, s: -1 });
+
+        for neighbor in hex.neighbors().iter() {
+            assert_eq!(hex.distance(*neighbor), 1);
+        }
+        assert_eq!(hex.diagonal_neighbor(0).distance(hex), 2);
+        assert_eq!(Hex::new(0, 0).distance(Hex::new(3, -5)), 5);
+    }
+
+    #[test]
+    fn hex_rings_and_lines() {
+        let center = Hex::new(1, 1);
+        assert_eq!(center.ring(0), vec![center]);
+
+        let ring = center.ring(3);
+        assert_eq!(ring.len(), 18);
+        assert!(ring.iter().all(|hex| hex.distance(center) == 3));
+
+        let spiral = center.spiral(2);
+        assert_eq!(spiral.len(), 19);
+        assert_eq!(spiral[0], center);
+
+        let line = Hex::new(0, 0).line_to(Hex::new(4, -2));
+        assert_eq!(line.len(), 5);
+        assert_eq!(line[4], Hex::new(4, -2));
+        for pair in line.windows(2) {
+            assert_eq!(pair[0].distance(pair[1]), 1);
+        }
+    }
+
+    #[test]
+    fn hex_rotation_and_reflection() {
+        let hex = Hex::new(2, -3);
+        assert_eq!(hex.rotate_left().rotate_right(), hex);
+        assert_eq!(hex.rotate_left(), Hex::new(1, -2).rotate_left() + Hex::new(1, -1).rotate_left());
+
+        let mut rotated = hex;
+        for _ in 0..6 {
+            rotated = rotated.rotate_right();
+        }
+        assert_eq!(rotated, hex);
+
+        let center = Hex::new(1, 0);
+        assert_eq!(Hex::new(2, 0).rotate_around(center, 1), Hex::new(2, -1));
+        assert_eq!(Hex::new(2, 0).rotate_around(center, -5), Hex::new(2, -1));
+
+        assert_eq!(hex.reflect_q(), Hex::new(2, 1));
+        assert_eq!(hex.reflect_r(), Hex::new(1, -3));
+        assert_eq!(hex.reflect_s(), Hex::new(-3, 2));
+    }
+
+    #[test]
+    fn hex_offset_and_pixel_conversion() {
+        let conventions = [HexOffset::OddR, HexOffset::EvenR, HexOffset::OddQ, HexOffset::EvenQ];
+        for convention in conventions.iter() {
+            for hex in Hex::new(0, 0).spiral(3) {
+                assert_eq!(Hex::from_offset(hex.to_offset(*convention), *convention), hex);
+            }
+        }
+        assert_eq!(Hex::new(-1, 3).to_offset(HexOffset::OddR), Vector2i::new(0, 3));
+        assert_eq!(Hex::new(3, -1).to_offset(HexOffset::EvenQ), Vector2i::new(3, 1));
+
+        for orientation in [HexOrientation::Pointy, HexOrientation::Flat].iter() {
+            let layout = HexLayout::new(*orientation, Vector2::new(10.0, 8.0), Vector2::new(5.0, -3.0));
+            for hex in Hex::new(0, 0).spiral(3) {
+                let pixel = layout.to_pixel(hex);
+                assert_eq!(layout.hex_at(pixel), hex);
+
+                for corner in layout.corners(hex).iter() {
+                    assert_eq!(layout.hex_at(pixel + (*corner - pixel) * 0.9), hex);
+                }
+            }
+        }
+
+        assert_eq!(FractionalHex::new(0.2, 0.1).round(), Hex::new(0, 0));
+        assert_eq!(FractionalHex::new(0.6, 0.1).round(), Hex::new(1, 0));
+    }
+
+
+    #[test]
+    fn morton_encoding() {
+        let morton = Morton2::default();
+        assert_eq!(morton.encode(Vector2i::new(0b101, 0b011)), Some(0b01_10_11));
+        assert_eq!(morton.encode(Vector2i::new(-1, 0)), None);
+        assert_eq!(morton.decode(0b01_10_11), Some(Vector2i::new(0b101, 0b011)));
+        assert_eq!(Morton2::new(Vector2i::new(i64::MIN, 0)).decode(1), None);
+
+        let centered = Morton2::centered();
+        for point in [Vector2i::new(-7, 3), Vector2i::new(i32::MIN as i64, i32::MAX as i64)].iter() {
+            assert_eq!(centered.decode(centered.encode(*point).unwrap()), Some(*point));
+        }
+        assert_eq!(centered.encode(Vector2i::new(1 << 31, 0)), None);
+
+        let morton = Morton3::new(Vector3i::new(4, 4, 4));
+        assert_eq!(morton.encode(Vector3i::new(-3, -4, -4)), Some(0b001));
+        assert_eq!(morton.encode(Vector3i::new(-4, -3, -4)), Some(0b010));
+        assert_eq!(morton.encode(Vector3i::new(1 << 21, 0, 0)), None);
+
+        let centered = Morton3::centered();
+        for point in [Vector3i::new(-5, 17, -1 << 20), Vector3i::new((1 << 20) - 1, 0, 3)].iter() {
+            assert_eq!(centered.decode(centered.encode(*point).unwrap()), Some(*point));
+        }
+    }
+
+    #[test]
+    fn hilbert_encoding() {
+        // Consecutive indices along the curve must be neighbouring cells
+        let curve = Hilbert2::centered(3);
+        let mut previous = curve.decode(0).unwrap();
+        for index in 1..64 {
+            let point = curve.decode(index).unwrap();
+            let step = point - previous;
+            assert_eq!(step.x.abs() + step.y.abs(), 1);
+            assert_eq!(curve.encode(point), Some(index));
+            previous = point;
+        }
+        assert_eq!(curve.encode(Vector2i::new(4, 0)), None);
+
+        let curve = Hilbert3::new(2, Vector3i::new(0, 0, 0));
+        let mut previous = curve.decode(0).unwrap();
+        for index in 1..64 {
+            let point = curve.decode(index).unwrap();
+            let step = point - previous;
+            assert_eq!(step.x.abs() + step.y.abs() + step.z.abs(), 1);
+            assert_eq!(curve.encode(point), Some(index));
+            previous = point;
+        }
+
+        let curve = Hilbert2::centered(32);
+        let point = Vector2i::new(-123_456_789, 987_654_321);
+        assert_eq!(curve.decode(curve.encode(point).unwrap()), Some(point));
+
+        let curve = Hilbert3::centered(21);
+        let point = Vector3i::new(-1 << 20, 12_345, (1 << 20) - 1);
+        assert_eq!(curve.decode(curve.encode(point).unwrap()), Some(point));
+        assert_eq!(Hilbert3::new(1, Vector3i::new(0, i64::MIN, 0)).decode(0b111), None);
+    }
+
+
+    #[test]
+    fn safe_normalization() {
+        assert_eq!(Vector3::new(0.0, 3.0, 4.0).try_normal(), Some(Vector3::new(0.0, 0.6, 0.8)));
+        assert_eq!(Vector3::new(0.0, 0.0, 0.0).try_normal(), None);
+        assert_eq!(Vector2::new(f64::NAN, 1.0).try_normal(), None);
+        assert_eq!(Vector4::new(0.0, 0.0, 0.0, 0.0).normal_or_zero(), Vector4::new(0.0, 0.0, 0.0, 0.0));
+        assert_eq!(Vector2::new(0.0, 0.0).normal_or(Vector2::new(1.0, 0.0)), Vector2::new(1.0, 0.0));
+
+        let unit = Unit::new(Vector3::new(2.0, 0.0, 0.0)).unwrap();
+        assert_eq!(*unit, Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(unit.len(), 1.0);
+        assert!(Unit::new(Vector3::new(0.0, 0.0, 0.0)).is_none());
+        assert_eq!((-Unit::<Vector2>::y_axis()).into_inner(), Vector2::new(0.0, -1.0));
+    }
+
+    #[test]
+    fn matrix_rotate_and_look_at() {
+        let rotation = Matrix4::rotated(Unit::<Vector3>::z_axis(), std::f64::consts::FRAC_PI_2);
+        let result = Vector4::new(1.0, 0.0, 0.0, 1.0) * rotation;
+        assert!((result - Vector4::new(0.0, 1.0, 0.0, 1.0)).len() < 1e-12);
+
+        let eye = Vector3::new(1.0, 2.0, 3.0);
+        assert!(Matrix4::try_look_at(eye, eye, Vector3::new(0.0, 1.0, 0.0)).is_none());
+        assert!(Matrix4::try_look_at(eye, Vector3::new(1.0, 0.0, 3.0), Vector3::new(0.0, 1.0, 0.0)).is_none());
+
+        let view = Matrix4::try_look_at(eye, Vector3::new(1.0, 2.0, 0.0), Vector3::new(0.0, 1.0, 0.0)).unwrap();
+        let target = Vector4::new(1.0, 2.0, 0.0, 1.0) * view;
+        assert!((target - Vector4::new(0.0, 0.0, -3.0, 1.0)).len() < 1e-12);
+    }
+
+
+    #[test]
+    fn vec3_reflect_refract() {
+        let up = Unit::<Vector3>::y_axis();
+        let incoming = Vector3::new(1.0, -1.0, 0.0);
+        assert_eq!(incoming.reflect(up), Vector3::new(1.0, 1.0, 0.0));
+
+        let straight = Vector3::new(0.0, -1.0, 0.0).refract(up, 1.0 / 1.5).unwrap();
+        assert!((straight - Vector3::new(0.0, -1.0, 0.0)).len() < 1e-12);
+
+        // Snell's law: sin(out) = eta * sin(in)
+        let incoming = Vector3::new(0.6, -0.8, 0.0);
+        let refracted = incoming.refract(up, 1.0 / 1.5).unwrap();
+        assert!((refracted.len() - 1.0).abs() < 1e-12);
+        assert!((refracted.x - 0.6 / 1.5).abs() < 1e-12);
+
+        assert_eq!(Vector3::new(0.8, -0.6, 0.0).refract(up, 1.5), None);
+        assert_eq!(Vector2::new(1.0, -1.0).reflect(Unit::<Vector2>::y_axis()), Vector2::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn vec3_projection_and_angles() {
+        let v = Vector3::new(3.0, 4.0, 5.0);
+        let axis = Vector3::new(2.0, 0.0, 0.0);
+        assert_eq!(v.project_onto(axis), Vector3::new(3.0, 0.0, 0.0));
+        assert_eq!(v.reject_from(axis), Vector3::new(0.0, 4.0, 5.0));
+        assert_eq!(v.project_onto(Vector3::new(0.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, 0.0));
+        assert_eq!(v.project_onto_plane(Unit::<Vector3>::z_axis()), Vector3::new(3.0, 4.0, 0.0));
+        assert_eq!(Vector2::new(3.0, 4.0).reject_from(Vector2::new(0.0, 1.0)), Vector2::new(3.0, 0.0));
+
+        let x = Vector3::new(1.0, 0.0, 0.0);
+        let y = Vector3::new(0.0, 2.0, 0.0);
+        let half_pi = std::f64::consts::FRAC_PI_2;
+        assert!((x.angle_between(y) - half_pi).abs() < 1e-12);
+        assert!((x.signed_angle(y, Unit::<Vector3>::z_axis()) - half_pi).abs() < 1e-12);
+        assert!((y.signed_angle(x + Vector3::new(0.0, 0.0, 7.0), Unit::<Vector3>::z_axis()) + half_pi).abs() < 1e-12);
+        assert!((Vector2::new(1.0, 1.0).angle_between(Vector2::new(-1.0, 1.0)) - half_pi).abs() < 1e-12);
+    }
+
+    #[test]
+    fn vec3_orthonormal_basis() {
+        let normals = [
+            Vector3::new(0.0, 0.0, 1.0), Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(1.0, 2.0, 3.0), Vector3::new(-4.0, 0.5, -0.1),
+        ];
+
+        for normal in normals.iter() {
+            let n = normal.normal();
+            let (tangent, bitangent) = normal.orthonormal_basis().unwrap();
+            assert!((tangent.len() - 1.0).abs() < 1e-12 && (bitangent.len() - 1.0).abs() < 1e-12);
+            assert!(tangent.dot(n).abs() < 1e-12 && bitangent.dot(n).abs() < 1e-12);
+            assert!((tangent.cross(bitangent) - n).len() < 1e-12);
+
+            let orthogonal = normal.any_orthogonal();
+            assert!((orthogonal.len() - 1.0).abs() < 1e-12 && orthogonal.dot(n).abs() < 1e-12);
+        }
+
+        assert_eq!(Vector3::new(0.0, 0.0, 0.0).orthonormal_basis(), None);
+        assert_eq!(Vector3::new(f64::NAN, 1.0, 0.0).orthonormal_basis(), None);
+        assert_eq!(Unit::<Vector3>::z_axis().orthonormal_basis(), Vector3::new(0.0, 0.0, 2.0).orthonormal_basis().unwrap());
+    }
+
+
+    #[test]
+    fn vec2_perp_and_cross() {
+        let a = Vector2::new(2.0, 1.0);
+        assert_eq!(a.perp(), Vector2::new(-1.0, 2.0));
+        assert_eq!(a.perp_cw(), Vector2::new(1.0, -2.0));
+        assert_eq!(a.perp().dot(a), 0.0);
+
+        assert_eq!(a.cross(Vector2::new(0.0, 1.0)), 2.0);
+        assert_eq!(a.cross(a * 3.0), 0.0);
+        assert_eq!(a.cross(a.perp()), a.square_length());
+    }
+
+    #[test]
+    fn vec2_polar_and_rotation() {
+        use std::f64::consts::{PI, FRAC_PI_2};
+
+        let v = Vector2::from_polar(2.0, FRAC_PI_2);
+        assert!((v - Vector2::new(0.0, 2.0)).len() < 1e-12);
+        let (radius, angle) = v.to_polar();
+        assert!((radius - 2.0).abs() < 1e-12 && (angle - FRAC_PI_2).abs() < 1e-12);
+
+        assert!((Vector2::from_angle(PI).angle() - PI).abs() < 1e-12);
+        assert!((Vector2::new(1.0, 0.0).rotate(FRAC_PI_2) - Vector2::new(0.0, 1.0)).len() < 1e-12);
+
+        let rotated = Vector2::new(2.0, 1.0).rotate_around(Vector2::new(1.0, 1.0), PI);
+        assert!((rotated - Vector2::new(0.0, 1.0)).len() < 1e-12);
+
+        let x = Vector2::new(1.0, 0.0);
+        assert!((x.angle_to(Vector2::new(0.0, 1.0)) - FRAC_PI_2).abs() < 1e-12);
+        assert!((x.angle_to(Vector2::new(0.0, -3.0)) + FRAC_PI_2).abs() < 1e-12);
+    }
+
+
+    #[test]
+    fn spherical_conversion() {
+        use std::f64::consts::FRAC_PI_2;
+
+        let vectors = [
+            Vector3::new(1.0, 2.0, 3.0), Vector3::new(-4.0, 0.5, -2.0),
+            Vector3::new(0.0, -3.0, 0.0), Vector3::new(0.0, 0.0, 0.0),
+        ];
+        for up in [UpAxis::Y, UpAxis::Z].iter() {
+            for v in vectors.iter() {
+                let spherical = Spherical::from_vector(*v, *up);
+                assert!((spherical.to_vector(*up) - *v).len() < 1e-12);
+
+                let cylindrical = Cylindrical::from_vector(*v, *up);
+                assert!((cylindrical.to_vector(*up) - *v).len() < 1e-12);
+                assert!((Spherical::from(cylindrical).to_vector(*up) - *v).len() < 1e-12);
+                assert!((Cylindrical::from(spherical).to_vector(*up) - *v).len() < 1e-12);
+            }
+        }
+
+        let y_up = Spherical::from_vector(Vector3::new(1.0, 0.0, 0.0), UpAxis::Y);
+        assert_eq!(y_up, Spherical::new(1.0, FRAC_PI_2, FRAC_PI_2));
+        let z_up = Spherical::from_vector(Vector3::new(0.0, 0.0, 2.0), UpAxis::Z);
+        assert_eq!(z_up, Spherical::new(2.0, 0.0, 0.0));
+
+        let cylindrical = Cylindrical::from_vector(Vector3::new(0.0, 2.0, 5.0), UpAxis::Z);
+        assert_eq!(cylindrical, Cylindrical::new(2.0, FRAC_PI_2, 5.0));
+        assert!((cylindrical.to_vector(UpAxis::Y) - Vector3::new(2.0, 5.0, 0.0)).len() < 1e-12);
+    }
+
+    #[test]
+    fn spherical_wrap_and_lerp() {
+        use std::f64::consts::PI;
+
+        let wrapped = Spherical::new(-2.0, 0.5, 0.0).wrap();
+        assert!((wrapped.radius - 2.0).abs() < 1e-12);
+        assert!((wrapped.polar - (PI - 0.5)).abs() < 1e-12);
+        assert!((wrapped.azimuth - PI).abs() < 1e-12);
+
+        let original = Spherical::new(3.0, -0.25, 7.0);
+        let wrapped = original.wrap();
+        assert!(wrapped.polar >= 0.0 && wrapped.polar <= PI);
+        assert!(wrapped.azimuth > -PI && wrapped.azimuth <= PI);
+        assert!((wrapped.to_vector(UpAxis::Y) - original.to_vector(UpAxis::Y)).len() < 1e-12);
+
+        let clamped = Spherical::new(1.0, 0.0, 0.0).clamp_polar(0.1, PI - 0.1);
+        assert_eq!(clamped.polar, 0.1);
+
+        // The azimuth interpolates across the seam at pi
+        let halfway = Spherical::new(1.0, 1.0, 3.0).lerp(Spherical::new(3.0, 2.0, -3.0), 0.5);
+        assert!((halfway.radius - 2.0).abs() < 1e-12 && (halfway.polar - 1.5).abs() < 1e-12);
+        assert!((halfway.azimuth.abs() - PI).abs() < 1e-12);
+    }
+
+
+    fn matrix_approx_eq(a: Matrix4, b: Matrix4) -> bool {
+        let a: [[f64; 4]; 4] = a.into();
+        let b: [[f64; 4]; 4] = b.into();
+        (0..4).all(|i| (0..4).all(|j| (a[i][j] - b[i][j]).abs() < 1e-9))
+    }
+
+    #[test]
+    fn quaternion_rotation() {
+        let axis = Unit::new(Vector3::new(1.0, 2.0, -0.5)).unwrap();
+        let q = Quaternion::from_axis_angle(axis, 1.2);
+        let m = Matrix4::rotated(axis, 1.2);
+        assert!(matrix_approx_eq(Matrix4::from(q), m));
+
+        let back = Quaternion::from_matrix(m);
+        assert!((back - q).len() < 1e-12 || (back + q).len() < 1e-12);
+
+        let v = Vector3::new(0.3, -2.0, 4.0);
+        let expected = Vector4::new(v.x, v.y, v.z, 0.0) * m;
+        assert!((q.rotate(v) - Vector3::from(expected)).len() < 1e-12);
+        assert!((q.conjugate().rotate(q.rotate(v)) - v).len() < 1e-12);
+
+        let half = Quaternion::identity().slerp(q, 0.5);
+        assert!(((half * half) - q).len() < 1e-12);
+    }
+
+    #[test]
+    fn transform_compose_and_invert() {
+        let rotation = Quaternion::from_axis_angle(Unit::<Vector3>::y_axis(), 0.7);
+        let a = Transform::new(Vector3::new(1.0, 2.0, 3.0), rotation, Vector3::new(2.0, 2.0, 2.0));
+        let b = Transform::new(Vector3::new(-4.0, 0.5, 1.0), rotation.conjugate(), Vector3::new(1.0, 3.0, 0.5));
+        let p = Vector3::new(0.25, -1.0, 2.0);
+
+        let m = Matrix4::from(a);
+        let expected = Vector4::new(p.x, p.y, p.z, 1.0) * m;
+        assert!((a.transform_point(p) - Vector3::from(expected)).len() < 1e-12);
+
+        assert!(((a * b).transform_point(p) - a.transform_point(b.transform_point(p))).len() < 1e-12);
+        assert!(matrix_approx_eq((a * b).to_matrix(), a.to_matrix() * b.to_matrix()));
+        assert!((a.inverse().transform_point(a.transform_point(p)) - p).len() < 1e-12);
+        assert!((a.transform_vector(p) - rotation.rotate(p * 2.0)).len() < 1e-12);
+    }
+
+    #[test]
+    fn matrix_decompose() {
+        let rotation = Quaternion::from_axis_angle(Unit::new(Vector3::new(1.0, 1.0, 0.0)).unwrap(), -2.0);
+        let transform = Transform::new(Vector3::new(5.0, -1.0, 2.0), rotation, Vector3::new(1.0, 2.0, 3.0));
+        let decomposed = transform.to_matrix().decompose().unwrap();
+        assert!((decomposed.translation - transform.translation).len() < 1e-12);
+        assert!((decomposed.scale - transform.scale).len() < 1e-12);
+        assert!(rotation.dot(decomposed.rotation).abs() > 1.0 - 1e-12);
+
+        let mirrored = Matrix4::scaled(Vector3::new(-1.0, 1.0, 1.0)).decompose().unwrap();
+        assert!(mirrored.scale.x < 0.0 && mirrored.scale.y < 0.0 && mirrored.scale.z < 0.0);
+        assert!(matrix_approx_eq(mirrored.to_matrix(), Matrix4::scaled(Vector3::new(-1.0, 1.0, 1.0))));
+
+        let shear = Matrix4::from([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.5, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        assert_eq!(shear.decompose(), Err(DecomposeError::Shear));
+        assert_eq!(Matrix4::scaled(Vector3::new(1.0, 0.0, 1.0)).decompose(), Err(DecomposeError::Singular));
+        assert_eq!(Matrix4::perspective(90.0, 1.0, 0.1, 10.0).decompose(), Err(DecomposeError::Projective));
+    }
+
+
+    #[test]
+    fn matrix_inverse() {
+        let m = Transform::new(
+            Vector3::new(1.0, -2.0, 3.0),
+            Quaternion::from_axis_angle(Unit::<Vector3>::x_axis(), 0.4),
+            Vector3::new(2.0, 1.0, 0.5)
+        ).to_matrix();
+
+        assert!((m.determinant() - 1.0).abs() < 1e-12);
+        assert!(matrix_approx_eq(m * m.inverse().unwrap(), Matrix4::new()));
+        assert!(Matrix4::scaled(Vector3::new(1.0, 0.0, 1.0)).inverse().is_none());
+    }
+
+    #[test]
+    fn hierarchy_world_matrices() {
+        let mut tree = TransformTree::new();
+        let root = tree.add(Transform::from_translation(Vector3::new(1.0, 0.0, 0.0)), None);
+        let child = tree.add(Transform::from_scale(Vector3::new(2.0, 2.0, 2.0)), Some(root));
+        let grandchild = tree.add(Transform::from_translation(Vector3::new(0.0, 1.0, 0.0)), Some(child));
+
+        let origin = Vector4::new(0.0, 0.0, 0.0, 1.0);
+        assert_eq!(origin * tree.world_matrix(grandchild), Vector4::new(1.0, 2.0, 0.0, 1.0));
+
+        tree.set_local(root, Transform::from_translation(Vector3::new(0.0, 0.0, 5.0)));
+        tree.update();
+        assert_eq!(origin * tree.world_matrix(grandchild), Vector4::new(0.0, 2.0, 5.0, 1.0));
+
+        let order: Vec<_> = tree.iter().collect();
+        assert_eq!(order, vec![root, child, grandchild]);
+        assert_eq!(tree.children(root), &[child]);
+
+        tree.remove(child);
+        assert_eq!(tree.len(), 1);
+        assert!(!tree.contains(grandchild));
+        let reused = tree.add(Transform::identity(), None);
+        assert!(reused != child && tree.contains(reused) && !tree.contains(child));
+
+        // Deep chains are resolved without recursion
+        let mut tree = TransformTree::new();
+        let mut leaf = tree.add(Transform::identity(), None);
+        for _ in 0..100_000 {
+            leaf = tree.add(Transform::from_translation(Vector3::new(1.0, 0.0, 0.0)), Some(leaf));
+        }
+        assert_eq!(origin * tree.world_matrix(leaf), Vector4::new(100_000.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn hierarchy_reparent() {
+        let mut tree = TransformTree::new();
+        let a = tree.add(Transform::from_translation(Vector3::new(10.0, 0.0, 0.0)), None);
+        let b = tree.add(Transform::new(
+            Vector3::new(0.0, 3.0, 0.0),
+            Quaternion::from_axis_angle(Unit::<Vector3>::z_axis(), 1.0),
+            Vector3::new(2.0, 2.0, 2.0)
+        ), None);
+        let c = tree.add(Transform::from_translation(Vector3::new(1.0, 1.0, 1.0)), Some(b));
+
+        assert_eq!(tree.set_parent(b, Some(c), false), Err(ReparentError::Cycle));
+
+        let before = tree.world_matrix(c);
+        tree.set_parent(b, Some(a), true).unwrap();
+        assert!(matrix_approx_eq(tree.world_matrix(c), before));
+        assert_eq!(tree.parent(b), Some(a));
+        assert_eq!(tree.roots(), &[a]);
+
+        tree.set_parent(c, None, false).unwrap();
+        let origin = Vector4::new(0.0, 0.0, 0.0, 1.0);
+        assert_eq!(origin * tree.world_matrix(c), Vector4::new(1.0, 1.0, 1.0, 1.0));
+        assert_eq!(tree.iter().collect::<Vec<_>>(), vec![a, b, c]);
+    }
+
+
+    #[test]
+    fn affine2_chaining() {
+        use std::f64::consts::FRAC_PI_2;
+
+        let transform = Affine2::translated(Vector2::new(10.0, 0.0))
+            .rotate(FRAC_PI_2)
+            .scale(Vector2::new(2.0, 3.0));
+
+        // Scale is applied first, then rotation, then translation
+        let p = transform.transform_point(Vector2::new(1.0, 1.0));
+        assert!((p - Vector2::new(7.0, 2.0)).len() < 1e-12);
+        assert!((transform.transform_vector(Vector2::new(1.0, 0.0)) - Vector2::new(0.0, 2.0)).len() < 1e-12);
+
+        let skew = Affine2::skewed(std::f64::consts::FRAC_PI_4, 0.0);
+        assert!((skew.transform_point(Vector2::new(0.0, 2.0)) - Vector2::new(2.0, 2.0)).len() < 1e-12);
+        assert!((Affine2::identity().skew(std::f64::consts::FRAC_PI_4, 0.0).x_axis - skew.x_axis).len() < 1e-12);
+    }
+
+    #[test]
+    fn affine2_inverse_and_conversion() {
+        let a = Affine2::translated(Vector2::new(1.0, -2.0)).rotate(0.3).skew(0.2, -0.1).scale(Vector2::new(2.0, 0.5));
+        let p = Vector2::new(3.0, 4.0);
+
+        let inverse = a.inverse().unwrap();
+        assert!((inverse.transform_point(a.transform_point(p)) - p).len() < 1e-12);
+        assert!(((a * inverse).transform_point(p) - p).len() < 1e-12);
+        assert!(Affine2::scaled(Vector2::new(0.0, 1.0)).inverse().is_none());
+
+        let m3 = a.to_matrix3();
+        let x = m3[0][0] * p.x + m3[1][0] * p.y + m3[2][0];
+        let y = m3[0][1] * p.x + m3[1][1] * p.y + m3[2][1];
+        assert!((Vector2::new(x, y) - a.transform_point(p)).len() < 1e-12);
+
+        let m4 = Vector4::new(p.x, p.y, 0.0, 1.0) * Matrix4::from(a);
+        assert!((Vector2::from(m4) - a.transform_point(p)).len() < 1e-12);
+    }
+
+
+    #[test]
+    fn isometry3_compose_and_invert() {
+        let a = Isometry3::new(
+            Quaternion::from_axis_angle(Unit::new(Vector3::new(1.0, -1.0, 2.0)).unwrap(), 0.8),
+            Vector3::new(1.0, 2.0, 3.0)
+        );
+        let b = Isometry3::new(Quaternion::from_axis_angle(Unit::<Vector3>::y_axis(), -1.3), Vector3::new(0.0, -5.0, 1.0));
+        let p = Vector3::new(-2.0, 0.5, 4.0);
+
+        assert!(((a * b).transform_point(p) - a.transform_point(b.transform_point(p))).len() < 1e-12);
+        assert!((a.inverse().transform_point(a.transform_point(p)) - p).len() < 1e-12);
+        assert!(((a.transform_point(p) - a.transform_point(b.translation)).len() - (p - b.translation).len()).abs() < 1e-12);
+
+        let expected = Vector4::new(p.x, p.y, p.z, 1.0) * Matrix4::from(a);
+        assert!((Vector3::from(expected) - a.transform_point(p)).len() < 1e-12);
+
+        let halfway = Isometry3::identity().lerp(Isometry3::from_translation(Vector3::new(2.0, 0.0, 0.0)), 0.5);
+        assert_eq!(halfway.translation, Vector3::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn similarity3_compose_and_invert() {
+        let rotation = Quaternion::from_axis_angle(Unit::<Vector3>::z_axis(), 0.5);
+        let a = Similarity3::new(2.0, rotation, Vector3::new(1.0, 0.0, -1.0));
+        let b = Similarity3::new(0.25, rotation.conjugate(), Vector3::new(3.0, 3.0, 3.0));
+        let p = Vector3::new(1.0, 2.0, 3.0);
+
+        assert!(((a * b).transform_point(p) - a.transform_point(b.transform_point(p))).len() < 1e-12);
+        assert!((a.inverse().transform_point(a.transform_point(p)) - p).len() < 1e-12);
+        assert!((Transform::from(a).transform_point(p) - a.transform_point(p)).len() < 1e-12);
+
+        let expected = Vector4::new(p.x, p.y, p.z, 1.0) * Matrix4::from(a);
+        assert!((Vector3::from(expected) - a.transform_point(p)).len() < 1e-12);
+    }
+
+    #[test]
+    fn isometry2_and_similarity2() {
+        use std::f64::consts::PI;
+
+        let a = Isometry2::new(0.5, Vector2::new(1.0, 2.0));
+        let b = Similarity2::new(3.0, -1.0, Vector2::new(-1.0, 0.0));
+        let p = Vector2::new(2.0, -3.0);
+
+        assert!((a.inverse().transform_point(a.transform_point(p)) - p).len() < 1e-12);
+        assert!((b.inverse().transform_point(b.transform_point(p)) - p).len() < 1e-12);
+
+        let sa = Similarity2::from(a);
+        assert!(((sa * b).transform_point(p) - a.transform_point(b.transform_point(p))).len() < 1e-12);
+        assert!((Affine2::from(b).transform_point(p) - b.transform_point(p)).len() < 1e-12);
+
+        // Rotation interpolates along the shortest arc
+        let halfway = Isometry2::new(PI - 0.1, p).lerp(Isometry2::new(-PI + 0.1, p), 0.5);
+        assert!((halfway.rotation - PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn dual_quaternion_transforms() {
+        let rotation = Quaternion::from_axis_angle(Unit::<Vector3>::y_axis(), 0.7);
+        let translation = Vector3::new(1.0, -2.0, 3.0);
+        let dq = DualQuaternion::from_rotation_translation(rotation, translation);
+        let p = Vector3::new(0.5, 1.5, -2.0);
+
+        assert!((dq.translation() - translation).len() < 1e-12);
+        assert!((dq.transform_point(p) - Isometry3::new(rotation, translation).transform_point(p)).len() < 1e-12);
+        assert!((dq.conjugate().transform_point(dq.transform_point(p)) - p).len() < 1e-12);
+
+        let other = DualQuaternion::from_rotation_translation(Quaternion::identity(), Vector3::new(0.0, 1.0, 0.0));
+        assert!(((dq * other).transform_point(p) - dq.transform_point(other.transform_point(p))).len() < 1e-12);
+
+        let from_matrix = DualQuaternion::from_matrix(Matrix4::from(dq)).unwrap();
+        assert!((from_matrix.transform_point(p) - dq.transform_point(p)).len() < 1e-9);
+        assert!(DualQuaternion::from_matrix(Matrix4::scaled(Vector3::new(2.0, 2.0, 2.0))).is_none());
+    }
+
+    #[test]
+    fn dual_quaternion_interpolation() {
+        let a = DualQuaternion::from_rotation_translation(Quaternion::identity(), Vector3::new(0.0, 0.0, 0.0));
+        let b = DualQuaternion::from_rotation_translation(
+            Quaternion::from_axis_angle(Unit::<Vector3>::z_axis(), 1.0),
+            Vector3::new(2.0, 0.0, 4.0),
+        );
+
+        assert!((a.sclerp(b, 0.0).transform_point(Vector3::new(1.0, 0.0, 0.0)) - Vector3::new(1.0, 0.0, 0.0)).len() < 1e-12);
+        let end = b.transform_point(Vector3::new(1.0, 0.0, 0.0));
+        assert!((a.sclerp(b, 1.0).transform_point(Vector3::new(1.0, 0.0, 0.0)) - end).len() < 1e-12);
+
+        // Screw motion: half the rotation angle and half the translation along the axis
+        let half = a.sclerp(b, 0.5);
+        assert!((half.rotation().dot(Quaternion::from_axis_angle(Unit::<Vector3>::z_axis(), 0.5)).abs() - 1.0).abs() < 1e-12);
+        assert!((half.translation().z - 2.0).abs() < 1e-12);
+
+        // Antipodal representations blend as the same rotation
+        let blended = DualQuaternion::blend(&[(b, 0.5), (-b, 0.5)]).unwrap();
+        assert!((blended.transform_point(Vector3::new(1.0, 0.0, 0.0)) - end).len() < 1e-12);
+        assert!(DualQuaternion::blend(&[]).is_none());
+
+        // The negated representation of a translation scales the same way
+        let shift = -DualQuaternion::from_rotation_translation(Quaternion::identity(), Vector3::new(2.0, 0.0, 4.0));
+        assert!((shift.pow(0.5).translation() - Vector3::new(1.0, 0.0, 2.0)).len() < 1e-12);
+        assert!((shift.pow(0.5).rotation().w - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn euler_round_trip() {
+        use std::f64::consts::{PI, FRAC_PI_2};
+
+        for &order in EulerOrder::ALL.iter() {
+            let middle = if order.is_proper() { 1.2 } else { -0.4 };
+            let angles = EulerAngles::new(order, 0.3, middle, -2.1);
+
+            let extracted = angles.to_matrix().to_euler(order);
+            assert!((extracted.to_vector() - angles.to_vector()).len() < 1e-9, "{:?}", order);
+
+            let from_quaternion = angles.to_quaternion().to_euler(order);
+            assert!((from_quaternion.to_vector() - angles.to_vector()).len() < 1e-9, "{:?}", order);
+
+            // Gimbal lock keeps the rotation, with the third angle folded into the first
+            let locked_middles = if order.is_proper() { [0.0, PI] } else { [FRAC_PI_2, -FRAC_PI_2] };
+            for &middle in locked_middles.iter() {
+                let locked = EulerAngles::new(order, 0.3, middle, -0.5);
+                let extracted = locked.to_matrix().to_euler(order);
+                assert_eq!(extracted.third, 0.0);
+                assert!(matrix_approx_eq(extracted.to_matrix(), locked.to_matrix()), "{:?} {}", order, middle);
+            }
+        }
+    }
+
+    #[test]
+    fn euler_known_angles() {
+        // Rx * Ry * Rz for XYZ, so the third rotation applies to the vector first
+        let angles = EulerAngles::new(EulerOrder::XYZ, 0.0, 0.0, 1.0);
+        let q = Quaternion::from_axis_angle(Unit::<Vector3>::z_axis(), 1.0);
+        assert!((Quaternion::from(angles).dot(q) - 1.0).abs() < 1e-12);
+
+        let yaw = Quaternion::from_axis_angle(Unit::<Vector3>::y_axis(), 0.5);
+        let pitch = Quaternion::from_axis_angle(Unit::<Vector3>::x_axis(), -0.25);
+        let extracted = (yaw * pitch).to_euler(EulerOrder::YXZ);
+        assert!((extracted.to_vector() - Vector3::new(0.5, -0.25, 0.0)).len() < 1e-12);
+    }
+
+    #[test]
+    fn rotation_vector_maps() {
+        let v = Vector3::new(0.3, -1.2, 0.8);
+        let q = Quaternion::from_rotation_vector(v);
+        let expected = Quaternion::from_axis_angle(Unit::new(v).unwrap(), v.len());
+
+        assert!((q.dot(expected) - 1.0).abs() < 1e-12);
+        assert!((q.to_rotation_vector() - v).len() < 1e-12);
+        assert!((Matrix4::from_rotation_vector(v).to_rotation_vector() - v).len() < 1e-9);
+
+        // Both signs of a quaternion give the same, shortest rotation vector
+        assert!(((-q).to_rotation_vector() - v).len() < 1e-12);
+
+        let tiny = Vector3::new(1e-10, 0.0, -2e-10);
+        assert!((Quaternion::from_rotation_vector(tiny).to_rotation_vector() - tiny).len() < 1e-18);
+
+        // Spinning at a constant rate for one second in small steps
+        let omega = Vector3::new(0.0, 0.0, 2.0);
+        let mut orientation = Quaternion::identity();
+        for _ in 0..100 {
+            orientation = orientation.integrate(omega, 0.01);
+        }
+        assert!((orientation.to_rotation_vector() - omega).len() < 1e-9);
+    }
+
+    #[test]
+    fn swing_twist_and_shortest_arc() {
+        let from = Vector3::new(1.0, 0.0, 0.0);
+        let to = Vector3::new(1.0, 1.0, 1.0);
+
+        let q = Quaternion::rotation_between(from, to).unwrap();
+        assert!((q.rotate(from) - to.normal()).len() < 1e-12);
+        assert!((q.to_rotation_vector().len() - from.angle_between(to)).abs() < 1e-12);
+
+        let flipped = Quaternion::rotation_between(from, -from).unwrap();
+        assert!((flipped.rotate(from) + from).len() < 1e-12);
+        assert!(Quaternion::rotation_between(from, Vector3::new(0.0, 0.0, 0.0)).is_none());
+
+        let axis = Unit::<Vector3>::y_axis();
+        let rotation = Quaternion::from_axis_angle(Unit::new(Vector3::new(1.0, 2.0, 0.5)).unwrap(), 1.3);
+        let (swing, twist) = rotation.swing_twist(axis);
+
+        assert!(((swing * twist).dot(rotation) - 1.0).abs() < 1e-12);
+        assert!(twist.vector().cross(*axis).len() < 1e-12);
+        assert!(swing.vector().dot(*axis).abs() < 1e-12);
+    }
+
+    #[test]
+    fn color_transfer_and_hex() {
+        for i in 0..=255 {
+            let byte = Rgb8::new(i, 255 - i, i / 2);
+            assert_eq!(byte.to_linear().to_srgb8(), byte);
+        }
+
+        assert!((srgb_to_linear(0.5) - 0.214_041_14).abs() < 1e-6);
+        assert!((linear_to_srgb(srgb_to_linear(0.02)) - 0.02).abs() < 1e-6);
+
+        assert_eq!(Rgba8::from_hex("#ff8000"), Ok(Rgba8::new(255, 128, 0, 255)));
+        assert_eq!("f80a".parse::<Rgba8>(), Ok(Rgba8::new(255, 136, 0, 170)));
+        assert_eq!(Rgb8::from_hex("#12345678"), Err(ParseColorError::Length));
+        assert_eq!(Rgb8::from_hex("#12g"), Err(ParseColorError::Digit));
+        assert_eq!(Rgb8::new(1, 171, 255).to_hex(), "#01abff");
+        assert_eq!(Rgba8::from_hex(&Rgba8::new(9, 8, 7, 6).to_hex()), Ok(Rgba8::new(9, 8, 7, 6)));
+
+        let shader: [f32; 4] = Rgba::from(Vector4::new(0.25, 0.5, 0.75, 1.0)).into();
+        assert_eq!(shader, [0.25, 0.5, 0.75, 1.0]);
+    }
+
+    #[test]
+    fn color_alpha_and_blending() {
+        let color = Rgba::new(0.8, 0.4, 0.2, 0.5);
+        let premultiplied = color.premultiply();
+        assert_eq!(premultiplied, Rgba::new(0.4, 0.2, 0.1, 0.5));
+        assert_eq!(premultiplied.unpremultiply(), color);
+
+        let backdrop = Rgba::new(0.2, 0.6, 1.0, 1.0);
+        let over = color.over(backdrop);
+        let expected = color.premultiply().over_premultiplied(backdrop.premultiply());
+        assert!((Vector4::from(over) - Vector4::from(expected)).len() < 1e-6);
+
+        let multiplied = Rgba::new(0.5, 0.5, 0.5, 1.0).blend(backdrop, BlendMode::Multiply);
+        assert!((Vector4::from(multiplied) - Vector4::new(0.1, 0.3, 0.5, 1.0)).len() < 1e-6);
+
+        // Over a transparent backdrop, blend modes have no effect
+        let transparent = Rgba::new(0.0, 0.0, 0.0, 0.0);
+        assert_eq!(color.blend(transparent, BlendMode::Difference), color);
+    }
+
+    #[test]
+    fn color_spaces() {
+        let colors = [
+            Rgb::new(1.0, 0.0, 0.0),
+            Rgb::new(0.2, 0.7, 0.4),
+            Rgb::new(0.9, 0.1, 0.8),
+            Rgb::new(0.5, 0.5, 0.5),
+        ];
+
+        for &color in colors.iter() {
+            let rgb = Vector3::from(color);
+            assert!((Vector3::from(Rgb::from(Hsv::from(color))) - rgb).len() < 1e-6);
+            assert!((Vector3::from(Rgb::from(Hsl::from(color))) - rgb).len() < 1e-6);
+            assert!((Vector3::from(Oklab::from_linear(color).to_linear()) - rgb).len() < 1e-5);
+        }
+
+        let hsv = Hsv::from(Rgb::new(0.2, 0.7, 0.4));
+        assert!((hsv.h - 144.0).abs() < 1e-4);
+
+        let white = Oklab::from_linear(Rgb::new(1.0, 1.0, 1.0));
+        assert!((white.l - 1.0).abs() < 1e-4 && white.a.abs() < 1e-4 && white.b.abs() < 1e-4);
+    }
+
+    #[test]
+    fn aabb_contains() {
+        let aabb = Aabb3::new(Vector3::new(-1.0, 0.0, 2.0), Vector3::new(1.0, 4.0, 3.0));
+        assert!(aabb.contains(aabb.center()));
+        assert!(aabb.contains(aabb.max));
+        assert!(!aabb.contains(Vector3::new(0.0, 5.0, 2.5)));
+        assert!((aabb.size() - Vector3::new(2.0, 4.0, 1.0)).len() == 0.0);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_distributions() {
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+        use rand::rngs::StdRng;
+        use random::*;
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let normal = Unit::new(Vector3::new(1.0, 2.0, -1.0)).unwrap();
+        let triangle = Triangle::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), Vector2::new(0.0, 2.0));
+        let aabb = Aabb3::new(Vector3::new(-1.0, 0.0, 2.0), Vector3::new(1.0, 4.0, 3.0));
+
+        let mut cosine_sum = 0.0;
+        for _ in 0..1000 {
+            assert!((UnitCircle.sample(&mut rng).len() - 1.0).abs() < 1e-12);
+            assert!(UnitDisk.sample(&mut rng).len() <= 1.0);
+            assert!((UnitSphere.sample(&mut rng).len() - 1.0).abs() < 1e-12);
+            assert!(UnitBall.sample(&mut rng).len() <= 1.0);
+            assert!(Hemisphere::new(normal).sample(&mut rng).dot(*normal) >= 0.0);
+
+            let cosine = CosineHemisphere::new(normal).sample(&mut rng);
+            assert!((cosine.len() - 1.0).abs() < 1e-12 && cosine.dot(*normal) >= 0.0);
+            cosine_sum += cosine.dot(*normal);
+
+            let p = triangle.sample(&mut rng);
+            assert!(p.x >= 0.0 && p.y >= 0.0 && p.x + p.y <= 2.0);
+            assert!(aabb.contains(aabb.sample(&mut rng)));
+            assert!((UniformRotation.sample(&mut rng).len() - 1.0).abs() < 1e-12);
+        }
+
+        // The mean cosine is 2/3 for cosine weighting, compared to 1/2 for uniform
+        assert!((cosine_sum / 1000.0 - 2.0 / 3.0).abs() < 0.03);
+
+        // Reproducible from the seed
+        let a: Vector3 = UnitSphere.sample(&mut StdRng::seed_from_u64(1));
+        let b: Vector3 = UnitSphere.sample(&mut StdRng::seed_from_u64(1));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn noise_gradients() {
+        use noise::*;
+
+        fn check<N: Noise<Vector3>>(noise: &N) {
+            let h = 1e-6;
+            for i in 0..50 {
+                let p = Vector3::new(i as f64 * 0.37 - 4.1, i as f64 * 0.71 + 0.2, i as f64 * -0.53);
+                let (value, gradient) = noise.value_and_gradient(p);
+
+                let numeric = Vector3::new(
+                    noise.value(p + Vector3::new(h, 0.0, 0.0)) - value,
+                    noise.value(p + Vector3::new(0.0, h, 0.0)) - value,
+                    noise.value(p + Vector3::new(0.0, 0.0, h)) - value,
+                ) / h;
+                assert!((numeric - gradient).len() < 1e-3, "{:?} {:?}", numeric, gradient);
+            }
+        }
+
+        check(&Perlin::new(3));
+        check(&Value::new(3));
+        check(&Simplex::new(3));
+        check(&Worley::new(3));
+        check(&Fbm::new(Perlin::new(3), 4));
+        check(&Fbm::new(Simplex::new(3), 3));
+
+        // 2D and 4D simplex share the same gradient code path as 3D
+        let p = Vector4::new(0.3, -1.7, 2.2, 0.9);
+        let (value, gradient) = Simplex::new(1).value_and_gradient(p);
+        let numeric = (Simplex::new(1).value(p + Vector4::new(0.0, 0.0, 1e-6, 0.0)) - value) / 1e-6;
+        assert!((numeric - gradient.z).abs() < 1e-3);
+
+        let p = Vector2::new(0.3, -1.7);
+        let (value, gradient) = Simplex::new(1).value_and_gradient(p);
+        let numeric = (Simplex::new(1).value(p + Vector2::new(1e-6, 0.0)) - value) / 1e-6;
+        assert!((numeric - gradient.x).abs() < 1e-3);
+    }
+
+    #[test]
+    fn noise_seeding_and_tiling() {
+        use noise::*;
+
+        let p = Vector2::new(1.3, 2.7);
+        assert_eq!(Perlin::new(5).value(p), Perlin::new(5).value(p));
+        assert!(Perlin::new(5).value(p) != Perlin::new(6).value(p));
+        assert_eq!(Perlin::new(5).value(Vector2::new(3.0, -2.0)), 0.0);
+
+        let period = Vector2::new(8.0, 0.0);
+        assert!((Perlin::tileable(1, 8).value(p) - Perlin::tileable(1, 8).value(p + period)).abs() < 1e-12);
+        assert!((Value::tileable(1, 8).value(p) - Value::tileable(1, 8).value(p - period)).abs() < 1e-12);
+        assert!((Worley::tileable(1, 8).value(p) - Worley::tileable(1, 8).value(p + period * 2.0)).abs() < 1e-12);
+
+        let turbulence = Turbulence::new(Perlin::tileable(1, 4), 3);
+        assert!((turbulence.value(p) - turbulence.value(p + Vector2::new(0.0, 4.0))).abs() < 1e-12);
+
+        for i in 0..1000 {
+            let p = Vector3::new(i as f64 * 0.123, i as f64 * 0.057, i as f64 * -0.311);
+            assert!(Perlin::new(0).value(p).abs() <= 1.1);
+            assert!(Simplex::new(0).value(p).abs() <= 1.1);
+            assert!(Value::new(0).value(p).abs() <= 1.0);
+            assert!(Worley::new(0).value(p) >= 0.0);
+
+            let ridged = Ridged::new(Perlin::new(0), 4).value(p);
+            assert!((0.0..=1.0).contains(&ridged));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "period must be positive")]
+    fn noise_rejects_zero_period() {
+        use noise::*;
+
+        Perlin::tileable(1, 0);
+    }
+
+    #[test]
+    fn low_discrepancy_sequences() {
+        use sequence::*;
+
+        assert_eq!(radical_inverse(2, 6), 0.375);
+        assert!((radical_inverse(3, 5) - 7.0 / 9.0).abs() < 1e-15);
+
+        let halton: Vec<Vector2> = Halton2::new().take(3).collect();
+        assert_eq!(halton[2], Vector2::new(0.25, radical_inverse(3, 2)));
+
+        let hammersley = Hammersley3::new(8);
+        assert_eq!(hammersley.len(), 8);
+        assert_eq!(hammersley.last(), Some(Vector3::new(7.0 / 8.0, 0.875, radical_inverse(3, 7))));
+
+        let sobol: Vec<Vector2> = Sobol2::new().take(4).collect();
+        assert_eq!(sobol, vec![
+            Vector2::new(0.0, 0.0), Vector2::new(0.5, 0.5),
+            Vector2::new(0.75, 0.25), Vector2::new(0.25, 0.75),
+        ]);
+
+        // The first 2^k points stratify every grid of 2^k cells, eg. 4x4 and 2x8
+        for &(columns, rows) in [(4, 4), (2, 8), (16, 1)].iter() {
+            let mut cells = [0; 16];
+            for p in Sobol2::new().take(16) {
+                cells[(p.y * rows as f64) as usize * columns + (p.x * columns as f64) as usize] += 1;
+            }
+            assert!(cells.iter().all(|&count| count == 1));
+        }
+
+        for p in Sobol3::new().take(64).chain(Halton3::new().take(64)) {
+            assert!((0.0..1.0).contains(&p.x) && (0.0..1.0).contains(&p.y) && (0.0..1.0).contains(&p.z));
+        }
+
+        let r2: Vec<Vector2> = R2::new().take(2).collect();
+        assert_eq!(r2[0], Vector2::new(0.5, 0.5));
+        assert!((r2[1].x - (0.5 + 1.0 / 1.324_717_957_244_746_f64).fract()).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 2")]
+    fn radical_inverse_rejects_base_one() {
+        use sequence::radical_inverse;
+
+        radical_inverse(1, 3);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn poisson_disk_and_jittered_grid() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+        use random::*;
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let bounds = Aabb2::new(Vector2::new(-1.0, 2.0), Vector2::new(4.0, 5.0));
+
+        let points = PoissonDisk2::new(bounds, 0.3).generate(&mut rng);
+        for (i, a) in points.iter().enumerate() {
+            assert!(bounds.contains(*a));
+            for b in points[i + 1..].iter() {
+                assert!(a.distance(*b) >= 0.3);
+            }
+        }
+
+        // Maximal: every point of the rectangle is within two radii of a sample
+        for p in JitteredGrid2::new(bounds, [20, 20]).generate(&mut rng) {
+            assert!(points.iter().any(|q| p.distance(*q) < 0.6));
+        }
+
+        let bounds3 = Aabb3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+        let points = PoissonDisk3::new(bounds3, 0.2).generate(&mut rng);
+        assert!(points.len() > 20);
+        for (i, a) in points.iter().enumerate() {
+            assert!(points[i + 1..].iter().all(|b| a.distance(*b) >= 0.2));
+        }
+
+        let grid = JitteredGrid3::new(bounds3, [2, 3, 4]).generate(&mut rng);
+        assert_eq!(grid.len(), 24);
+        assert!(grid.iter().all(|p| bounds3.contains(*p)));
+
+        let centers = JitteredGrid2 { jitter: 0.0, ..JitteredGrid2::new(bounds, [5, 3]) }.generate(&mut rng);
+        assert_eq!(centers[0], Vector2::new(-0.5, 2.5));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn poisson_disk_rejects_unusable_bounds() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+        use random::*;
+
+        let mut rng = StdRng::seed_from_u64(11);
+
+        let infinite = Aabb2::new(Vector2::new(0.0, 0.0), Vector2::new(f64::INFINITY, 1.0));
+        assert!(PoissonDisk2::new(infinite, 0.3).generate(&mut rng).is_empty());
+
+        let nan = Aabb2::new(Vector2::new(f64::NAN, 0.0), Vector2::new(1.0, 1.0));
+        assert!(PoissonDisk2::new(nan, 0.3).generate(&mut rng).is_empty());
+
+        let extreme = Aabb2::new(Vector2::new(-f64::MAX, 0.0), Vector2::new(f64::MAX, 1.0));
+        assert!(PoissonDisk2::new(extreme, 0.3).generate(&mut rng).is_empty());
+
+        let huge = Aabb3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1e6, 1e6, 1e6));
+        assert!(PoissonDisk3::new(huge, 1e-3).generate(&mut rng).is_empty());
+    }
+
+    #[test]
+    fn vector_sum_and_product() {
+        let points = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0), Vector3::new(-1.0, 0.5, 2.0)];
+
+        assert_eq!(points.iter().sum::<Vector3>(), Vector3::new(4.0, 7.5, 11.0));
+        assert_eq!(points.iter().copied().sum::<Vector3>(), Vector3::new(4.0, 7.5, 11.0));
+        assert_eq!(points.iter().product::<Vector3>(), Vector3::new(-4.0, 5.0, 36.0));
+        assert_eq!(Vec::<Vector2>::new().into_iter().sum::<Vector2>(), Vector2::new(0.0, 0.0));
+        assert_eq!(Vec::<Vector4>::new().into_iter().product::<Vector4>(), Vector4::new(1.0, 1.0, 1.0, 1.0));
+
+        let cells = [Vector2i::new(1, -2), Vector2i::new(3, 4)];
+        assert_eq!(cells.iter().sum::<Vector2i>(), Vector2i::new(4, 2));
+        assert_eq!(cells.iter().copied().product::<Vector2i>(), Vector2i::new(3, -8));
+        assert_eq!([Vector4i::new(1, 2, 3, 4); 3].iter().sum::<Vector4i>(), Vector4i::new(3, 6, 9, 12));
+    }
+
+    #[test]
+    fn vector_centroid_and_bounds() {
+        let points = [Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0), Vector2::new(2.0, 3.0)];
+        assert_eq!(Vector2::centroid(points.iter().copied()), Some(Vector2::new(2.0, 1.0)));
+        assert_eq!(Vector3::centroid(Vec::new()), None);
+
+        let weighted = vec![(Vector3::new(0.0, 0.0, 0.0), 1.0), (Vector3::new(4.0, 8.0, 0.0), 3.0)];
+        assert_eq!(Vector3::weighted_average(weighted), Some(Vector3::new(3.0, 6.0, 0.0)));
+        assert_eq!(Vector3::weighted_average(vec![(Vector3::new(1.0, 1.0, 1.0), 0.0)]), None);
+
+        let bounds = Aabb2::from_points(points.iter().copied()).unwrap();
+        assert_eq!(bounds, Aabb2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 3.0)));
+        assert_eq!(Aabb3::from_points(Vec::<Vector3>::new()), None);
+        assert!(Aabb3::empty().is_empty());
+
+        // Arrays and tuples convert into vectors, so they collect into bounds directly
+        let from_tuples: Aabb3 = vec![(1.0, -2.0, 3.0), (-1.0, 5.0, 0.0)].into_iter().collect();
+        assert_eq!(from_tuples, Aabb3::new(Vector3::new(-1.0, -2.0, 0.0), Vector3::new(1.0, 5.0, 3.0)));
+
+        let mut from_arrays: Aabb2 = vec![[1.0, 1.0]].into_iter().collect();
+        from_arrays.extend(vec![[-2.0, 0.5], [0.0, 4.0]]);
+        assert_eq!(from_arrays, Aabb2::new(Vector2::new(-2.0, 0.5), Vector2::new(1.0, 4.0)));
+
+        let (x, y): (f64, f64) = Vector2::from((2.5, -1.0)).into();
+        assert_eq!((x, y), (2.5, -1.0));
+
+        let cells = vec![Vector3i::new(1, 5, -2), Vector3i::new(-3, 2, 0), Vector3i::new(0, 7, 1)];
+        assert_eq!(Vector3i::bounds(cells), Some((Vector3i::new(-3, 2, -2), Vector3i::new(1, 7, 1))));
+        assert_eq!(Vector2i::bounds(Vec::new()), None);
+    }
+
+    #[test]
+    fn vector_indexing_and_iteration() {
+        let mut v = Vector3::new(1.0, -5.0, 2.0);
+        assert_eq!(v[0], 1.0);
+        assert_eq!(v[Axis3::Y], -5.0);
+        assert_eq!(v.as_array(), &[1.0, -5.0, 2.0]);
+        assert_eq!(v.dominant_axis(), Axis3::Y);
+
+        v[2] = 4.0;
+        v[Axis3::X] += 1.0;
+        for component in &mut v {
+            *component *= 2.0;
+        }
+        assert_eq!(v, Vector3::new(4.0, -10.0, 8.0));
+        assert_eq!(v.iter().sum::<f64>(), 2.0);
+        assert_eq!(v.into_iter().collect::<Vec<_>>(), vec![4.0, -10.0, 8.0]);
+
+        let w = Vector4::new(0.5, 1.0, 1.0, -1.0);
+        assert_eq!(w.dominant_axis(), Axis::Y);
+        let array: &[f64; 4] = w.as_ref();
+        assert_eq!(array[3], -1.0);
+
+        let mut cell = Vector2i::new(3, -7);
+        cell.as_mut_array()[0] = 9;
+        assert_eq!(cell[Axis2::X], 9);
+        assert_eq!(cell.dominant_axis(), Axis2::X);
+        assert_eq!(Vector4i::new(1, 2, 3, i64::MIN).dominant_axis(), Axis::W);
+        assert_eq!((&Vector3i::new(1, 2, 3)).into_iter().max(), Some(&3));
+
+        assert_eq!(Axis::from_index(2), Some(Axis::Z));
+        assert_eq!(Axis::from_index(4), None);
+        assert_eq!(usize::from(Axis::W), 3);
+        assert_eq!(Axis2::from_index(2), None);
+        assert_eq!(Axis3::from_index(2), Some(Axis3::Z));
+        assert_eq!(Axis::from(Axis3::from(Axis2::Y)), Axis::Y);
+    }
+
+    #[test]
+    fn vector_display_and_parse() {
+        let v = Vector3::new(1.0, -2.5, 1.0 / 3.0);
+        assert_eq!(format!("{:.3}", v), "(1.000, -2.500, 0.333)");
+        assert_eq!(format!("{}", Vector2::new(1.0, 0.5)), "(1, 0.5)");
+        assert_eq!(format!("{:.2}", Vector3i::new(1, -2, 3)), "(1, -2, 3)");
+        assert_eq!(v.to_string().parse::<Vector3>(), Ok(v));
+
+        assert_eq!("(1, 2, 3)".parse::<Vector3>(), Ok(Vector3::new(1.0, 2.0, 3.0)));
+        assert_eq!("  1 2\t3 ".parse::<Vector3>(), Ok(Vector3::new(1.0, 2.0, 3.0)));
+        assert_eq!("[1,2,3]".parse::<Vector3>(), Ok(Vector3::new(1.0, 2.0, 3.0)));
+        assert_eq!("[-4, 1e2]".parse::<Vector2>(), Ok(Vector2::new(-4.0, 100.0)));
+        assert_eq!("(1, 2, 3, 4)".parse::<Vector4i>(), Ok(Vector4i::new(1, 2, 3, 4)));
+
+        let error = |position, kind| Err(ParseVectorError { position, kind });
+        assert_eq!("(1, x, 3)".parse::<Vector3>(), error(4, ParseVectorErrorKind::Number));
+        assert_eq!("(1, 2.5)".parse::<Vector2i>().unwrap_err().position, 4);
+        assert_eq!("1,,3".parse::<Vector3>(), error(2, ParseVectorErrorKind::Missing));
+        assert_eq!("(1, 2)".parse::<Vector3>(), error(5, ParseVectorErrorKind::Count { expected: 3, found: 2 }));
+        assert_eq!("1 2 3 4 5".parse::<Vector3>(), error(6, ParseVectorErrorKind::Count { expected: 3, found: 5 }));
+        assert_eq!("(1, 2, 3]".parse::<Vector3>(), error(8, ParseVectorErrorKind::Bracket));
+        assert_eq!("(1, 2, 3".parse::<Vector3>(), error(0, ParseVectorErrorKind::Bracket));
+
+        let message = "(1, 2)".parse::<Vector3>().unwrap_err().to_string();
+        assert_eq!(message, "expected 3 components, found 2 at position 5");
+    }
+
+    #[test]
+    fn matrix_display_and_parse() {
+        let m = Matrix4::translated(Vector3::new(1.0, 2.0, 3.0));
+        let printed = format!("{:.1}", m);
+        assert_eq!(printed, "[1.0, 0.0, 0.0, 1.0]\n[0.0, 1.0, 0.0, 2.0]\n[0.0, 0.0, 1.0, 3.0]\n[0.0, 0.0, 0.0, 1.0]");
+        assert_eq!(printed.parse::<Matrix4>(), Ok(m));
+
+        let flat = "1 0 0 1  0 1 0 2  0 0 1 3  0 0 0 1";
+        assert_eq!(flat.parse::<Matrix4>(), Ok(m));
+        let nested = "[[1, 0, 0, 1], [0, 1, 0, 2], [0, 0, 1, 3], [0, 0, 0, 1]]";
+        assert_eq!(nested.parse::<Matrix4>(), Ok(m));
+
+        let error = "[1, 0, 0, 1] [0, 1, 0, 2] [0, 0, 1, 3]".parse::<Matrix4>().unwrap_err();
+        assert_eq!(error.kind, ParseVectorErrorKind::Count { expected: 4, found: 3 });
+        let error = "[1, 0, 0] [0, 1, 0, 2] [0, 0, 1, 3] [0, 0, 0, 1]".parse::<Matrix4>().unwrap_err();
+        assert_eq!(error, ParseVectorError { position: 8, kind: ParseVectorErrorKind::Count { expected: 4, found: 3 } });
+    }
+
+    #[test]
+    fn ordered_vector_keys() {
+        use std::cmp::Ordering;
+        use std::collections::{BTreeMap, HashMap, HashSet};
+
+        let mut welded = HashMap::new();
+        for (i, point) in [Vector3::new(0.0, 1.0, 2.0), Vector3::new(-0.0, 1.0, 2.0), Vector3::new(0.0, 1.0, 2.5)].iter().enumerate() {
+            welded.entry(Ordered::new(*point)).or_insert(i);
+        }
+        assert_eq!(welded.len(), 2);
+        assert_eq!(welded[&Ordered::new(Vector3::new(0.0, 1.0, 2.0))], 0);
+
+        let nan = Ordered::new(Vector2::new(f64::NAN, 1.0));
+        assert_eq!(nan, Ordered::new(Vector2::new(-f64::NAN, 1.0)));
+        assert!(Ordered::new(Vector2::new(5.0, 1.0)) < nan);
+
+        let mut sorted = vec![Vector2::new(1.0, 2.0), Vector2::new(-0.0, 3.0), Vector2::new(0.0, -1.0), Vector2::new(1.0, 1.0)];
+        sorted.sort_by(Vector2::total_cmp);
+        assert_eq!(sorted, vec![Vector2::new(-0.0, 3.0), Vector2::new(0.0, -1.0), Vector2::new(1.0, 1.0), Vector2::new(1.0, 2.0)]);
+        assert_eq!(Vector4::new(1.0, 2.0, 3.0, 4.0).total_cmp(&Vector4::new(1.0, 2.0, 3.0, 4.0)), Ordering::Equal);
+
+        let tolerance = 1e-3;
+        let keys: HashSet<Vector3i> = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0001, 2.0, 2.9999), Vector3::new(1.01, 2.0, 3.0)]
+            .iter()
+            .map(|point| point.quantize(tolerance).unwrap())
+            .collect();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(Vector2::new(0.26, -0.74).quantize(0.5), Some(Vector2i::new(1, -1)));
+        assert_eq!(Vector2::new(f64::NAN, 0.0).quantize(0.5), None);
+        assert_eq!(Vector3::new(1.0, 2.0, 3.0).quantize(0.0), None);
+        assert_eq!(Vector4::new(1e300, 0.0, 0.0, 0.0).quantize(1e-3), None);
+
+        let mut cells = BTreeMap::new();
+        cells.insert(Vector2i::new(1, 0), 'b');
+        cells.insert(Vector2i::new(0, 5), 'a');
+        cells.insert(Vector2i::new(1, -3), 'c');
+        assert_eq!(cells.keys().copied().collect::<Vec<_>>(), vec![Vector2i::new(0, 5), Vector2i::new(1, -3), Vector2i::new(1, 0)]);
+        assert!(Vector3i::new(0, 0, 1) < Vector3i::new(0, 1, 0));
+    }
+
+    #[test]
+    #[cfg(feature = "mint")]
+    fn mint_conversions() {
+        use mint;
+
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        let m: mint::Vector3<f64> = v.into();
+        assert_eq!((m.x, m.y, m.z), (1.0, 2.0, 3.0));
+        assert_eq!(Vector3::from(m), v);
+        assert_eq!(Vector2i::from(mint::Vector2::from([4_i64, -5])), Vector2i::new(4, -5));
+
+        let translation = Matrix4::translated(v);
+        let columns: mint::ColumnMatrix4<f64> = translation.into();
+        assert_eq!((columns.w.x, columns.w.y, columns.w.z), (1.0, 2.0, 3.0));
+        let rows: mint::RowMatrix4<f64> = translation.into();
+        assert_eq!((rows.x.w, rows.y.w, rows.z.w), (1.0, 2.0, 3.0));
+        assert_eq!(Matrix4::from(rows), translation);
+        assert_eq!(Matrix4::from(columns), translation);
+    }
+
+    #[test]
+    #[cfg(feature = "glam")]
+    fn glam_conversions() {
+        use glam;
+
+        let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(glam::DVec4::from(v), glam::DVec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Vector3::from(glam::Vec3::new(0.5, 1.5, 2.5)), Vector3::new(0.5, 1.5, 2.5));
+        assert_eq!(Vector3i::from(glam::I64Vec3::new(1, 2, 3)), Vector3i::new(1, 2, 3));
+
+        let translation = Matrix4::translated(Vector3::new(1.0, 2.0, 3.0));
+        let converted = glam::DMat4::from(translation);
+        assert_eq!(converted, glam::DMat4::from_translation(glam::DVec3::new(1.0, 2.0, 3.0)));
+        assert_eq!(Matrix4::from(converted), translation);
+        assert_eq!(Matrix4::from(glam::Mat4::from(translation)), translation);
+    }
+
+    #[test]
+    #[cfg(feature = "nalgebra")]
+    fn nalgebra_conversions() {
+        use nalgebra;
+
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(nalgebra::Vector3::from(v), nalgebra::Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector3::from(nalgebra::Point3::new(1.0, 2.0, 3.0)), v);
+        assert_eq!(Vector4i::from(nalgebra::Vector4::new(1_i64, 2, 3, 4)), Vector4i::new(1, 2, 3, 4));
+
+        let translation = Matrix4::translated(v);
+        let converted = nalgebra::Matrix4::from(translation);
+        assert_eq!(converted, nalgebra::Matrix4::new_translation(&nalgebra::Vector3::new(1.0, 2.0, 3.0)));
+        assert_eq!(converted[(0, 3)], 1.0);
+        assert_eq!(Matrix4::from(converted), translation);
+    }
+
+    #[test]
+    #[cfg(feature = "cgmath")]
+    fn cgmath_conversions() {
+        use cgmath;
+
+        let v = Vector2::new(1.0, 2.0);
+        assert_eq!(cgmath::Vector2::from(v), cgmath::Vector2::new(1.0, 2.0));
+        assert_eq!(Vector3::from(cgmath::Point3::new(1.0, 2.0, 3.0)), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector2i::from(cgmath::Vector2::new(1_i64, 2)), Vector2i::new(1, 2));
+
+        let translation = Matrix4::translated(Vector3::new(1.0, 2.0, 3.0));
+        let converted = cgmath::Matrix4::from(translation);
+        assert_eq!(converted, cgmath::Matrix4::from_translation(cgmath::Vector3::new(1.0, 2.0, 3.0)));
+        assert_eq!(Matrix4::from(converted), translation);
+    }
+
+    #[test]
+    fn float_to_integer_vector_conversions() {
+        use std::convert::TryFrom;
+
+        let v = Vector3::new(1.5, -1.5, 2.0);
+        assert_eq!(Vector3i::floor(v), Vector3i::new(1, -2, 2));
+        assert_eq!(Vector3i::round(v), Vector3i::new(2, -2, 2));
+        assert_eq!(Vector3i::ceil(v), Vector3i::new(2, -1, 2));
+        assert_eq!(Vector3i::trunc(v), Vector3i::new(1, -1, 2));
+        assert_eq!(Vector2i::floor(Vector2::new(f64::NAN, 1e300)), Vector2i::new(0, i64::MAX));
+
+        assert_eq!(Vector3i::try_from_float(v), Ok(Vector3i::new(1, -1, 2)));
+        assert_eq!(Vector3i::from(v), Vector3i::trunc(v));
+        assert_eq!(Vector2i::try_from_float(Vector2::new(1.0, f64::NAN)), Err(TryFromVectorError::NaN));
+        assert_eq!(Vector4i::try_from_float(Vector4::new(0.0, 0.0, f64::INFINITY, 0.0)), Err(TryFromVectorError::OutOfRange));
+        assert_eq!(Vector2i::try_from_float(Vector2::new(9.3e18, 0.0)), Err(TryFromVectorError::OutOfRange));
+        assert_eq!(Vector2i::try_from_float(Vector2::new(-9_223_372_036_854_775_808.0, 0.0)), Ok(Vector2i::new(i64::MIN, 0)));
+
+        let cell = Vector3i::new(300, 2, -1);
+        assert_eq!(<[i32; 3]>::try_from(cell), Ok([300, 2, -1]));
+        assert_eq!(<[u32; 3]>::try_from(cell), Err(TryFromVectorError::OutOfRange));
+        assert_eq!(<[u16; 2]>::try_from(Vector2i::new(300, 2)), Ok([300, 2]));
+        assert_eq!(<[u8; 2]>::try_from(Vector2i::new(300, 2)), Err(TryFromVectorError::OutOfRange));
+        assert_eq!(Vector4i::from([1_u8, 2, 3, 255]), Vector4i::new(1, 2, 3, 255));
+        assert_eq!(Vector3i::from([u32::MAX, 0, 0]), Vector3i::new(4_294_967_295, 0, 0));
+    }
+
+    #[test]
+    fn vector_extension_and_homogeneous_coordinates() {
+        let v = Vector2::new(1.0, 2.0);
+        assert_eq!(v.extend(3.0), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(v.extend(3.0).extend(4.0).truncate().truncate(), v);
+        assert_eq!(v.to_homogeneous_point(), Vector3::new(1.0, 2.0, 1.0));
+        assert_eq!(v.to_homogeneous_vector(), Vector3::new(1.0, 2.0, 0.0));
+        assert_eq!(Vector2::from_homogeneous(Vector3::new(2.0, 4.0, 2.0)), Some(v));
+        assert_eq!(Vector2::from_homogeneous(Vector3::new(2.0, 4.0, 0.0)), None);
+
+        // Translation moves points but not directions
+        let p = Vector3::new(1.0, 2.0, 3.0);
+        let translation = Matrix4::translated(Vector3::new(10.0, 0.0, 0.0));
+        let moved = p.to_homogeneous_point() * translation;
+        assert_eq!(Vector3::from_homogeneous(moved), Some(Vector3::new(11.0, 2.0, 3.0)));
+        assert_eq!((p.to_homogeneous_vector() * translation).truncate(), p);
+        assert_eq!(Vector3::from_homogeneous(Vector4::new(2.0, 4.0, 6.0, 0.5)), Some(Vector3::new(4.0, 8.0, 12.0)));
+        assert_eq!(Vector3::from_homogeneous(p.to_homogeneous_vector()), None);
+
+        let cell = Vector3i::new(2, -4, 6);
+        assert_eq!(cell.truncate(), Vector2i::new(2, -4));
+        assert_eq!(cell.truncate().extend(6), cell);
+        assert_eq!(cell.to_homogeneous_point(), Vector4i::new(2, -4, 6, 1));
+        assert_eq!(cell.to_homogeneous_vector().truncate(), cell);
+        assert_eq!(Vector4i::new(1, 2, 3, 4).truncate(), Vector3i::new(1, 2, 3));
+        assert_eq!(Vector3i::from_homogeneous(Vector4i::new(2, -4, 6, 2)), Some(Vector3i::new(1, -2, 3)));
+        assert_eq!(Vector3i::from_homogeneous(Vector4i::new(2, -4, 5, 2)), None);
+        assert_eq!(Vector3i::from_homogeneous(Vector4i::new(2, -4, 6, 0)), None);
+        assert_eq!(Vector2i::from_homogeneous(Vector3i::new(i64::MIN, 0, -1)), None);
+        assert_eq!(Vector2i::from_homogeneous(Vector3i::new(6, 3, -3)), Some(Vector2i::new(-2, -1)));
+    }
 }
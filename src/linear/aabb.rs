@@ -0,0 +1,155 @@
+use std::iter::FromIterator;
+use super::{Vector2, Vector3};
+
+/// An axis-aligned bounding rectangle, containing the points between `min` and `max` inclusive
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Aabb2 {
+    pub min: Vector2,
+    pub max: Vector2,
+}
+
+
+/// An axis-aligned bounding box, containing the points between `min` and `max` inclusive
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Aabb3 {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+
+impl Aabb2 {
+    /// Create a new rectangle
+    pub fn new(min: Vector2, max: Vector2) -> Aabb2 {
+        Aabb2 { min, max }
+    }
+
+    /// A rectangle containing nothing, which grows to fit the first point included
+    pub fn empty() -> Aabb2 {
+        let infinity = Vector2::new(f64::INFINITY, f64::INFINITY);
+        Aabb2 { min: infinity, max: -infinity }
+    }
+
+    /// The smallest rectangle containing all points, or `None` if there are none
+    pub fn from_points<I, P>(points: I) -> Option<Aabb2>
+        where I: IntoIterator<Item = P>,
+              P: Into<Vector2>
+    {
+        let aabb: Aabb2 = points.into_iter().collect();
+        if aabb.is_empty() { None } else { Some(aabb) }
+    }
+
+    /// Whether the rectangle contains no points, ie. `min` exceeds `max` along some axis
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y
+    }
+
+    /// Grow to contain a point
+    pub fn include(&mut self, point: Vector2) {
+        self.min = self.min.component_min(point);
+        self.max = self.max.component_max(point);
+    }
+
+    /// Extent along each axis
+    pub fn size(&self) -> Vector2 {
+        self.max - self.min
+    }
+
+    /// Midpoint of the rectangle
+    pub fn center(&self) -> Vector2 {
+        (self.min + self.max) / 2.0
+    }
+
+    /// Whether a point lies inside or on the boundary
+    pub fn contains(&self, point: Vector2) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x &&
+            self.min.y <= point.y && point.y <= self.max.y
+    }
+}
+
+
+impl Aabb3 {
+    /// Create a new box
+    pub fn new(min: Vector3, max: Vector3) -> Aabb3 {
+        Aabb3 { min, max }
+    }
+
+    /// A box containing nothing, which grows to fit the first point included
+    pub fn empty() -> Aabb3 {
+        let infinity = Vector3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+        Aabb3 { min: infinity, max: -infinity }
+    }
+
+    /// The smallest box containing all points, or `None` if there are none
+    pub fn from_points<I, P>(points: I) -> Option<Aabb3>
+        where I: IntoIterator<Item = P>,
+              P: Into<Vector3>
+    {
+        let aabb: Aabb3 = points.into_iter().collect();
+        if aabb.is_empty() { None } else { Some(aabb) }
+    }
+
+    /// Whether the box contains no points, ie. `min` exceeds `max` along some axis
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    /// Grow to contain a point
+    pub fn include(&mut self, point: Vector3) {
+        self.min = self.min.component_min(point);
+        self.max = self.max.component_max(point);
+    }
+
+    /// Extent along each axis
+    pub fn size(&self) -> Vector3 {
+        self.max - self.min
+    }
+
+    /// Midpoint of the box
+    pub fn center(&self) -> Vector3 {
+        (self.min + self.max) / 2.0
+    }
+
+    /// Whether a point lies inside or on the boundary
+    pub fn contains(&self, point: Vector3) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x &&
+            self.min.y <= point.y && point.y <= self.max.y &&
+            self.min.z <= point.z && point.z <= self.max.z
+    }
+}
+
+
+// ITERATION
+
+impl<P: Into<Vector2>> FromIterator<P> for Aabb2 {
+    fn from_iter<I: IntoIterator<Item = P>>(points: I) -> Self {
+        let mut aabb = Aabb2::empty();
+        aabb.extend(points);
+        aabb
+    }
+}
+
+impl<P: Into<Vector2>> Extend<P> for Aabb2 {
+    fn extend<I: IntoIterator<Item = P>>(&mut self, points: I) {
+        for point in points {
+            self.include(point.into());
+        }
+    }
+}
+
+impl<P: Into<Vector3>> FromIterator<P> for Aabb3 {
+    fn from_iter<I: IntoIterator<Item = P>>(points: I) -> Self {
+        let mut aabb = Aabb3::empty();
+        aabb.extend(points);
+        aabb
+    }
+}
+
+impl<P: Into<Vector3>> Extend<P> for Aabb3 {
+    fn extend<I: IntoIterator<Item = P>>(&mut self, points: I) {
+        for point in points {
+            self.include(point.into());
+        }
+    }
+}
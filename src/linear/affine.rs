@@ -0,0 +1,182 @@
+use std::ops::{Mul, MulAssign};
+use super::{Vector2, Matrix4};
+
+/// A 2D affine transform: a 2x2 linear part followed by a translation.
+///
+/// The linear part is stored as the images of the x and y axes.
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Affine2 {
+    pub x_axis: Vector2,
+    pub y_axis: Vector2,
+    pub translation: Vector2,
+}
+
+
+impl Affine2 {
+    /// Create a new transform from its columns
+    pub fn new(x_axis: Vector2, y_axis: Vector2, translation: Vector2) -> Affine2 {
+        Affine2 { x_axis, y_axis, translation }
+    }
+
+    /// The identity transform
+    pub fn identity() -> Affine2 {
+        Affine2 {
+            x_axis: Vector2::new(1.0, 0.0),
+            y_axis: Vector2::new(0.0, 1.0),
+            translation: Vector2::new(0.0, 0.0),
+        }
+    }
+
+
+    pub fn translated(amount: Vector2) -> Affine2 {
+        Affine2 { translation: amount, ..Affine2::identity() }
+    }
+
+    /// Rotation of `angle` radians counter-clockwise
+    pub fn rotated(angle: f64) -> Affine2 {
+        let (sin, cos) = angle.sin_cos();
+        Affine2 {
+            x_axis: Vector2::new(cos, sin),
+            y_axis: Vector2::new(-sin, cos),
+            translation: Vector2::new(0.0, 0.0),
+        }
+    }
+
+    pub fn scaled(amount: Vector2) -> Affine2 {
+        Affine2 {
+            x_axis: Vector2::new(amount.x, 0.0),
+            y_axis: Vector2::new(0.0, amount.y),
+            translation: Vector2::new(0.0, 0.0),
+        }
+    }
+
+    /// Skew by the angles in radians that the y axis leans towards x, and the x axis
+    /// leans towards y
+    pub fn skewed(angle_x: f64, angle_y: f64) -> Affine2 {
+        Affine2 {
+            x_axis: Vector2::new(1.0, angle_y.tan()),
+            y_axis: Vector2::new(angle_x.tan(), 1.0),
+            translation: Vector2::new(0.0, 0.0),
+        }
+    }
+
+
+    pub fn translate(self, amount: Vector2) -> Affine2 {
+        self * Affine2::translated(amount)
+    }
+
+    pub fn rotate(self, angle: f64) -> Affine2 {
+        self * Affine2::rotated(angle)
+    }
+
+    pub fn scale(self, amount: Vector2) -> Affine2 {
+        self * Affine2::scaled(amount)
+    }
+
+    pub fn skew(self, angle_x: f64, angle_y: f64) -> Affine2 {
+        self * Affine2::skewed(angle_x, angle_y)
+    }
+
+
+    /// Transform a point
+    pub fn transform_point(&self, point: Vector2) -> Vector2 {
+        self.transform_vector(point) + self.translation
+    }
+
+    /// Transform a direction, ignoring translation
+    pub fn transform_vector(&self, vector: Vector2) -> Vector2 {
+        self.x_axis * vector.x + self.y_axis * vector.y
+    }
+
+
+    /// The determinant of the linear part
+    pub fn determinant(&self) -> f64 {
+        self.x_axis.cross(self.y_axis)
+    }
+
+    /// The inverse transform, or `None` if it is singular
+    pub fn inverse(&self) -> Option<Affine2> {
+        let determinant = self.determinant();
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        let x_axis = Vector2::new(self.y_axis.y, -self.x_axis.y) / determinant;
+        let y_axis = Vector2::new(-self.y_axis.x, self.x_axis.x) / determinant;
+        let translation = -(x_axis * self.translation.x + y_axis * self.translation.y);
+
+        Some(Affine2 { x_axis, y_axis, translation })
+    }
+
+
+    /// The equivalent column-major 3x3 matrix
+    pub fn to_matrix3(&self) -> [[f64; 3]; 3] {
+        [
+            [self.x_axis.x, self.x_axis.y, 0.0],
+            [self.y_axis.x, self.y_axis.y, 0.0],
+            [self.translation.x, self.translation.y, 1.0],
+        ]
+    }
+}
+
+
+impl Default for Affine2 {
+    fn default() -> Self {
+        Affine2::identity()
+    }
+}
+
+
+// MULTIPLICATION
+
+// Composition, `a * b` applies `b` first and then `a`
+impl Mul<Affine2> for Affine2 {
+    type Output = Affine2;
+
+    fn mul(self, rhs: Affine2) -> Self::Output {
+        Affine2 {
+            x_axis: self.transform_vector(rhs.x_axis),
+            y_axis: self.transform_vector(rhs.y_axis),
+            translation: self.transform_point(rhs.translation),
+        }
+    }
+}
+
+impl MulAssign<Affine2> for Affine2 {
+    fn mul_assign(&mut self, rhs: Affine2) {
+        *self = *self * rhs;
+    }
+}
+
+
+// CONVERSION
+
+impl From<Affine2> for [[f64; 3]; 3] {
+    fn from(affine: Affine2) -> Self {
+        affine.to_matrix3()
+    }
+}
+
+impl From<Affine2> for [[f32; 3]; 3] {
+    fn from(affine: Affine2) -> Self {
+        let m = affine.to_matrix3();
+        [
+            [m[0][0] as f32, m[0][1] as f32, m[0][2] as f32],
+            [m[1][0] as f32, m[1][1] as f32, m[1][2] as f32],
+            [m[2][0] as f32, m[2][1] as f32, m[2][2] as f32],
+        ]
+    }
+}
+
+impl From<Affine2> for Matrix4 {
+    fn from(affine: Affine2) -> Self {
+        let Affine2 { x_axis, y_axis, translation } = affine;
+        [
+            [x_axis.x, x_axis.y, 0.0, 0.0],
+            [y_axis.x, y_axis.y, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [translation.x, translation.y, 0.0, 1.0],
+        ].into()
+    }
+}
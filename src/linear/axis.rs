@@ -0,0 +1,117 @@
+/// A coordinate axis of a 2D vector, usable to index its components
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+pub enum Axis2 {
+    X,
+    Y,
+}
+
+
+/// A coordinate axis of a 3D vector, usable to index its components
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+pub enum Axis3 {
+    X,
+    Y,
+    Z,
+}
+
+
+/// A coordinate axis of a 4D vector, usable to index its components
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+    W,
+}
+
+
+impl Axis2 {
+    /// All axes in component order
+    pub const ALL: [Axis2; 2] = [Axis2::X, Axis2::Y];
+
+    /// Index of the component along this axis
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The axis of a component index, or `None` past the second component
+    pub fn from_index(index: usize) -> Option<Axis2> {
+        Axis2::ALL.get(index).copied()
+    }
+}
+
+
+impl Axis3 {
+    /// All axes in component order
+    pub const ALL: [Axis3; 3] = [Axis3::X, Axis3::Y, Axis3::Z];
+
+    /// Index of the component along this axis
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The axis of a component index, or `None` past the third component
+    pub fn from_index(index: usize) -> Option<Axis3> {
+        Axis3::ALL.get(index).copied()
+    }
+}
+
+
+impl Axis {
+    /// All axes in component order
+    pub const ALL: [Axis; 4] = [Axis::X, Axis::Y, Axis::Z, Axis::W];
+
+    /// Index of the component along this axis
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The axis of a component index, or `None` past the fourth component
+    pub fn from_index(index: usize) -> Option<Axis> {
+        Axis::ALL.get(index).copied()
+    }
+}
+
+
+impl From<Axis2> for usize {
+    fn from(axis: Axis2) -> Self {
+        axis.index()
+    }
+}
+
+impl From<Axis3> for usize {
+    fn from(axis: Axis3) -> Self {
+        axis.index()
+    }
+}
+
+impl From<Axis> for usize {
+    fn from(axis: Axis) -> Self {
+        axis.index()
+    }
+}
+
+
+/// The same axis in three dimensions
+impl From<Axis2> for Axis3 {
+    fn from(axis: Axis2) -> Self {
+        Axis3::ALL[axis.index()]
+    }
+}
+
+/// The same axis in four dimensions
+impl From<Axis2> for Axis {
+    fn from(axis: Axis2) -> Self {
+        Axis::ALL[axis.index()]
+    }
+}
+
+/// The same axis in four dimensions
+impl From<Axis3> for Axis {
+    fn from(axis: Axis3) -> Self {
+        Axis::ALL[axis.index()]
+    }
+}
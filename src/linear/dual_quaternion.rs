@@ -0,0 +1,249 @@
+use std::ops::{
+    Add,
+    Mul, MulAssign,
+    Neg,
+};
+use super::{Vector3, Matrix4, Quaternion, Isometry3};
+
+/// Rigid transforms with a scale within this distance of one are accepted by `from_matrix`
+const RIGID_TOLERANCE: f64 = 1e-6;
+
+
+/// A dual quaternion `real + ε dual`, used to represent rigid transforms.
+///
+/// Unit dual quaternions blend without the volume loss of linear blend skinning.
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct DualQuaternion {
+    pub real: Quaternion,
+    pub dual: Quaternion,
+}
+
+
+impl DualQuaternion {
+    /// Create a new dual quaternion
+    pub fn new(real: Quaternion, dual: Quaternion) -> DualQuaternion {
+        DualQuaternion { real, dual }
+    }
+
+    /// The identity transform
+    pub fn identity() -> DualQuaternion {
+        DualQuaternion {
+            real: Quaternion::identity(),
+            dual: Quaternion::new(0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+
+    /// A rotation followed by a translation
+    pub fn from_rotation_translation(rotation: Quaternion, translation: Vector3) -> DualQuaternion {
+        let t = Quaternion::new(translation.x, translation.y, translation.z, 0.0);
+        DualQuaternion {
+            real: rotation,
+            dual: t * rotation * 0.5,
+        }
+    }
+
+    /// The rigid transform of a matrix, or `None` if the matrix is not rigid
+    pub fn from_matrix(matrix: Matrix4) -> Option<DualQuaternion> {
+        let transform = matrix.decompose().ok()?;
+        let scale = transform.scale - Vector3::new(1.0, 1.0, 1.0);
+        if scale.len() > RIGID_TOLERANCE {
+            return None;
+        }
+
+        Some(DualQuaternion::from_rotation_translation(transform.rotation, transform.translation))
+    }
+
+
+    /// The rotation part
+    pub fn rotation(&self) -> Quaternion {
+        self.real
+    }
+
+    /// The translation part
+    pub fn translation(&self) -> Vector3 {
+        (self.dual * self.real.conjugate()).vector() * 2.0
+    }
+
+
+    /// The quaternion conjugate of both parts, which is the inverse of a unit dual quaternion
+    pub fn conjugate(&self) -> DualQuaternion {
+        DualQuaternion {
+            real: self.real.conjugate(),
+            dual: self.dual.conjugate(),
+        }
+    }
+
+    /// Normalize to a unit dual quaternion, a valid rigid transform
+    pub fn normal(&self) -> DualQuaternion {
+        let len = self.real.len();
+        let real = self.real * (1.0 / len);
+        let dual = self.dual * (1.0 / len);
+
+        // Remove the component of the dual part that breaks orthogonality
+        DualQuaternion {
+            real,
+            dual: dual - real * real.dot(dual),
+        }
+    }
+
+
+    /// Transform a point
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        self.real.rotate(point) + self.translation()
+    }
+
+    /// Transform a direction or normal, ignoring translation
+    pub fn transform_vector(&self, vector: Vector3) -> Vector3 {
+        self.real.rotate(vector)
+    }
+
+
+    /// Dual quaternion linear blending (DLB) of weighted transforms, eg. for skinning.
+    ///
+    /// Returns `None` if there are no transforms or the weights cancel out.
+    pub fn blend(weighted: &[(DualQuaternion, f64)]) -> Option<DualQuaternion> {
+        let pivot = weighted.first()?.0.real;
+
+        let zero = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+        let mut sum = DualQuaternion::new(zero, zero);
+        for &(dq, weight) in weighted {
+            // Keep all rotations in the same hemisphere as the first one
+            let weight = if dq.real.dot(pivot) < 0.0 { -weight } else { weight };
+            sum = sum + dq * weight;
+        }
+
+        let len = sum.real.len();
+        if len == 0.0 || !len.is_finite() {
+            None
+        } else {
+            Some(sum.normal())
+        }
+    }
+
+
+    /// Raise a unit dual quaternion to a power, scaling the screw motion it represents the
+    /// shorter way around
+    pub fn pow(&self, t: f64) -> DualQuaternion {
+        // Both signs represent the same motion; a negative real part would read as a full turn
+        let dq = if self.real.w < 0.0 { -*self } else { *self };
+        let axis = dq.real.vector();
+        let sin_half = axis.len();
+
+        // Pure translations scale linearly
+        if sin_half < 1e-12 {
+            return DualQuaternion {
+                real: Quaternion::identity(),
+                dual: dq.dual * t,
+            };
+        }
+
+        // Screw parameters: rotation angle and direction, pitch and moment
+        let half_angle = sin_half.atan2(dq.real.w);
+        let direction = axis / sin_half;
+        let half_pitch = -dq.dual.w / sin_half;
+        let moment = (dq.dual.vector() - direction * (half_pitch * dq.real.w)) / sin_half;
+
+        let (sin, cos) = (half_angle * t).sin_cos();
+        let half_pitch = half_pitch * t;
+        let real = direction * sin;
+        let dual = moment * sin + direction * (half_pitch * cos);
+
+        DualQuaternion {
+            real: Quaternion::new(real.x, real.y, real.z, cos),
+            dual: Quaternion::new(dual.x, dual.y, dual.z, -half_pitch * sin),
+        }
+    }
+
+    /// Screw linear interpolation (ScLERP) between unit dual quaternions, along the shortest path
+    pub fn sclerp(&self, other: DualQuaternion, t: f64) -> DualQuaternion {
+        let other = if self.real.dot(other.real) < 0.0 { -other } else { other };
+        *self * (self.conjugate() * other).pow(t)
+    }
+}
+
+
+impl Default for DualQuaternion {
+    fn default() -> Self {
+        DualQuaternion::identity()
+    }
+}
+
+
+// ADDITION
+
+impl Add<DualQuaternion> for DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn add(self, rhs: DualQuaternion) -> Self::Output {
+        DualQuaternion {
+            real: self.real + rhs.real,
+            dual: self.dual + rhs.dual,
+        }
+    }
+}
+
+
+// MULTIPLICATION
+
+// Composition, `a * b` applies `b` first and then `a`
+impl Mul<DualQuaternion> for DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn mul(self, rhs: DualQuaternion) -> Self::Output {
+        DualQuaternion {
+            real: self.real * rhs.real,
+            dual: self.real * rhs.dual + self.dual * rhs.real,
+        }
+    }
+}
+
+impl Mul<f64> for DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        DualQuaternion {
+            real: self.real * rhs,
+            dual: self.dual * rhs,
+        }
+    }
+}
+
+impl MulAssign<DualQuaternion> for DualQuaternion {
+    fn mul_assign(&mut self, rhs: DualQuaternion) {
+        *self = *self * rhs;
+    }
+}
+
+
+// NEGATION
+
+impl Neg for DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn neg(self) -> Self::Output {
+        DualQuaternion { real: -self.real, dual: -self.dual }
+    }
+}
+
+
+// CONVERSION
+
+impl From<Isometry3> for DualQuaternion {
+    fn from(isometry: Isometry3) -> Self {
+        DualQuaternion::from_rotation_translation(isometry.rotation, isometry.translation)
+    }
+}
+
+impl From<DualQuaternion> for Isometry3 {
+    fn from(dq: DualQuaternion) -> Self {
+        Isometry3::new(dq.rotation(), dq.translation())
+    }
+}
+
+impl From<DualQuaternion> for Matrix4 {
+    fn from(dq: DualQuaternion) -> Self {
+        Isometry3::from(dq).to_matrix()
+    }
+}
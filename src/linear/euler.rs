@@ -0,0 +1,185 @@
+use super::{Vector3, Matrix4, Quaternion, Unit};
+
+/// Below this cosine (Tait-Bryan) or sine (proper Euler) of the middle angle, the first and
+/// third axes are considered aligned
+const GIMBAL_TOLERANCE: f64 = 1e-9;
+
+
+/// The axes and order of three successive rotations.
+///
+/// `XYZ` means a rotation around X, then around the rotated Y, then around the twice rotated
+/// Z (intrinsic), which is the matrix `Rx * Ry * Rz`. Orders repeating the first axis are
+/// proper Euler angles, the others are Tait-Bryan angles.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+
+/// Three rotation angles in radians, applied around the axes of an `EulerOrder`
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct EulerAngles {
+    pub order: EulerOrder,
+    pub first: f64,
+    pub second: f64,
+    pub third: f64,
+}
+
+
+impl EulerOrder {
+    /// All twelve orders
+    pub const ALL: [EulerOrder; 12] = [
+        EulerOrder::XYZ, EulerOrder::XZY, EulerOrder::YXZ,
+        EulerOrder::YZX, EulerOrder::ZXY, EulerOrder::ZYX,
+        EulerOrder::XYX, EulerOrder::XZX, EulerOrder::YXY,
+        EulerOrder::YZY, EulerOrder::ZXZ, EulerOrder::ZYZ,
+    ];
+
+    /// Whether the first and last axes are the same
+    pub fn is_proper(self) -> bool {
+        let [first, _, third] = self.axes();
+        first == third
+    }
+
+    /// Indices of the rotation axes, 0 for X, 1 for Y and 2 for Z
+    fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        }
+    }
+}
+
+
+impl EulerAngles {
+    /// Create new Euler angles
+    pub fn new(order: EulerOrder, first: f64, second: f64, third: f64) -> EulerAngles {
+        EulerAngles { order, first, second, third }
+    }
+
+
+    /// Extract the angles of a matrix whose upper 3x3 part is a pure rotation.
+    ///
+    /// The middle angle is in `[-pi/2, pi/2]` for Tait-Bryan orders and `[0, pi]` for proper
+    /// orders, the others are in `[-pi, pi]`. In gimbal lock only the sum or difference of the
+    /// first and third angles is determined, so the third angle is set to zero.
+    pub fn from_matrix(matrix: Matrix4, order: EulerOrder) -> EulerAngles {
+        let m: [[f64; 4]; 4] = matrix.into();
+
+        // m is stored column-major, so m[col][row]
+        let r = |row: usize, col: usize| m[col][row];
+
+        let [i, j, _] = order.axes();
+        let k = 3 - i - j;
+
+        // +1 for cyclic axis orders such as XYZ, -1 for the others
+        let sign = if (j + 3 - i) % 3 == 1 { 1.0 } else { -1.0 };
+
+        let (second, locked) = if order.is_proper() {
+            let sin = r(i, j).hypot(r(i, k));
+            (sin.atan2(r(i, i)), sin < GIMBAL_TOLERANCE)
+        } else {
+            let cos = r(i, i).hypot(r(i, j));
+            ((sign * r(i, k)).atan2(cos), cos < GIMBAL_TOLERANCE)
+        };
+
+        let (first, third) = if locked {
+            // The middle rotation leaves axis j fixed, so with no third rotation the j column
+            // is only turned by the first rotation
+            ((sign * r(k, j)).atan2(r(j, j)), 0.0)
+        } else if order.is_proper() {
+            (r(j, i).atan2(-sign * r(k, i)), r(i, j).atan2(sign * r(i, k)))
+        } else {
+            ((-sign * r(j, k)).atan2(r(k, k)), (-sign * r(i, j)).atan2(r(i, i)))
+        };
+
+        EulerAngles { order, first, second, third }
+    }
+
+    /// Extract the angles of a unit quaternion, as with `from_matrix`
+    pub fn from_quaternion(rotation: Quaternion, order: EulerOrder) -> EulerAngles {
+        EulerAngles::from_matrix(rotation.into(), order)
+    }
+
+
+    /// The equivalent rotation
+    pub fn to_quaternion(&self) -> Quaternion {
+        let axis = |index: usize| match index {
+            0 => Unit::<Vector3>::x_axis(),
+            1 => Unit::<Vector3>::y_axis(),
+            _ => Unit::<Vector3>::z_axis(),
+        };
+
+        let [i, j, k] = self.order.axes();
+        Quaternion::from_axis_angle(axis(i), self.first) *
+            Quaternion::from_axis_angle(axis(j), self.second) *
+            Quaternion::from_axis_angle(axis(k), self.third)
+    }
+
+    /// The equivalent rotation matrix
+    pub fn to_matrix(&self) -> Matrix4 {
+        self.to_quaternion().into()
+    }
+
+
+    /// The angles as a vector of `(first, second, third)`
+    pub fn to_vector(&self) -> Vector3 {
+        Vector3::new(self.first, self.second, self.third)
+    }
+}
+
+
+impl Matrix4 {
+    /// Extract the Euler angles of a rotation matrix, see `EulerAngles::from_matrix`
+    pub fn to_euler(&self, order: EulerOrder) -> EulerAngles {
+        EulerAngles::from_matrix(*self, order)
+    }
+}
+
+
+impl Quaternion {
+    /// Extract the Euler angles of a unit quaternion, see `EulerAngles::from_matrix`
+    pub fn to_euler(&self, order: EulerOrder) -> EulerAngles {
+        EulerAngles::from_quaternion(*self, order)
+    }
+}
+
+
+// CONVERSION
+
+impl From<EulerAngles> for Quaternion {
+    fn from(angles: EulerAngles) -> Self {
+        angles.to_quaternion()
+    }
+}
+
+impl From<EulerAngles> for Matrix4 {
+    fn from(angles: EulerAngles) -> Self {
+        angles.to_matrix()
+    }
+}
@@ -0,0 +1,349 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use super::{Vector2, Vector3, Vector4, Vector2i, Vector3i, Vector4i, Matrix4};
+
+/// An error from parsing a vector or matrix, at a byte position in the input
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+pub struct ParseVectorError {
+    pub position: usize,
+    pub kind: ParseVectorErrorKind,
+}
+
+
+/// Why a vector or matrix failed to parse
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+pub enum ParseVectorErrorKind {
+    /// A component is not a number
+    Number,
+
+    /// Two commas, or a comma and a bracket, have no component between them
+    Missing,
+
+    /// There are too few or too many components
+    Count { expected: usize, found: usize },
+
+    /// A bracket is unmatched, or appears where it is not allowed
+    Bracket,
+}
+
+
+impl ParseVectorError {
+    fn new(position: usize, kind: ParseVectorErrorKind) -> ParseVectorError {
+        ParseVectorError { position, kind }
+    }
+}
+
+
+/// Write components as `(x, y, z)`, passing on the formatter's precision
+fn write_components<T: fmt::Display>(f: &mut fmt::Formatter, open: char, close: char, components: &[T]) -> fmt::Result {
+    write!(f, "{}", open)?;
+    for (i, component) in components.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}", precision, component)?,
+            None => write!(f, "{}", component)?,
+        }
+    }
+    write!(f, "{}", close)
+}
+
+fn is_open(c: char) -> bool {
+    c == '(' || c == '['
+}
+
+fn is_close(c: char) -> bool {
+    c == ')' || c == ']'
+}
+
+/// Byte index of the bracket closing the one that `s` starts with
+fn matching_close(s: &str, offset: usize) -> Result<usize, ParseVectorError> {
+    let mut stack = Vec::new();
+    for (i, c) in s.char_indices() {
+        if is_open(c) {
+            stack.push(c);
+        } else if is_close(c) {
+            let expected = match stack.pop() {
+                Some('(') => ')',
+                Some(_) => ']',
+                None => return Err(ParseVectorError::new(offset + i, ParseVectorErrorKind::Bracket)),
+            };
+            if c != expected {
+                return Err(ParseVectorError::new(offset + i, ParseVectorErrorKind::Bracket));
+            }
+            if stack.is_empty() {
+                return Ok(i);
+            }
+        }
+    }
+    Err(ParseVectorError::new(offset, ParseVectorErrorKind::Bracket))
+}
+
+/// Trim whitespace, and one pair of brackets if they enclose the whole string. Returns the
+/// inner string and its offset in the input.
+fn strip_brackets(s: &str, offset: usize) -> Result<(&str, usize), ParseVectorError> {
+    let trimmed = s.trim_start();
+    let offset = offset + s.len() - trimmed.len();
+    let trimmed = trimmed.trim_end();
+
+    match trimmed.chars().next() {
+        Some(c) if is_open(c) => {
+            let close = matching_close(trimmed, offset)?;
+            if close + 1 == trimmed.len() {
+                Ok((&trimmed[1..close], offset + 1))
+            } else {
+                Ok((trimmed, offset))
+            }
+        }
+        _ => Ok((trimmed, offset)),
+    }
+}
+
+/// Parse components separated by commas, or by whitespace if there are no commas
+fn parse_components<T, const N: usize>(s: &str, offset: usize) -> Result<[T; N], ParseVectorError>
+    where T: FromStr + Copy + Default
+{
+    let mut components = [T::default(); N];
+    let mut found = 0;
+    let mut first_extra = None;
+
+    let mut parse = |start: usize, token: &str| {
+        if let Some(i) = token.find(|c| is_open(c) || is_close(c)) {
+            return Err(ParseVectorError::new(offset + start + i, ParseVectorErrorKind::Bracket));
+        }
+        if found < N {
+            components[found] = token.parse()
+                .map_err(|_| ParseVectorError::new(offset + start, ParseVectorErrorKind::Number))?;
+        } else if first_extra.is_none() {
+            first_extra = Some(offset + start);
+        }
+        found += 1;
+        Ok(())
+    };
+
+    if s.contains(',') {
+        let mut start = 0;
+        for piece in s.split(',') {
+            let token = piece.trim();
+            let token_start = start + piece.len() - piece.trim_start().len();
+            if token.is_empty() {
+                return Err(ParseVectorError::new(offset + token_start, ParseVectorErrorKind::Missing));
+            }
+            parse(token_start, token)?;
+            start += piece.len() + 1;
+        }
+    } else {
+        let mut start = None;
+        for (i, c) in s.char_indices().chain(Some((s.len(), ' '))) {
+            match (c.is_whitespace(), start) {
+                (true, Some(token_start)) => {
+                    parse(token_start, &s[token_start..i])?;
+                    start = None;
+                }
+                (false, None) => start = Some(i),
+                _ => {}
+            }
+        }
+    }
+
+    if found != N {
+        let position = first_extra.unwrap_or(offset + s.len());
+        return Err(ParseVectorError::new(position, ParseVectorErrorKind::Count { expected: N, found }));
+    }
+    Ok(components)
+}
+
+/// Parse a vector, optionally enclosed in parentheses or square brackets
+fn parse_vector<T, const N: usize>(s: &str) -> Result<[T; N], ParseVectorError>
+    where T: FromStr + Copy + Default
+{
+    let (inner, offset) = strip_brackets(s, 0)?;
+    parse_components(inner, offset)
+}
+
+/// Parse a matrix in row-major order, either as 16 numbers or as 4 bracketed rows
+fn parse_matrix(s: &str) -> Result<[[f64; 4]; 4], ParseVectorError> {
+    let (inner, offset) = strip_brackets(s, 0)?;
+    if !inner.contains(|c| is_open(c) || is_close(c)) {
+        let components: [f64; 16] = parse_components(inner, offset)?;
+        let mut rows = [[0.0; 4]; 4];
+        for (i, component) in components.iter().enumerate() {
+            rows[i / 4][i % 4] = *component;
+        }
+        return Ok(rows);
+    }
+
+    let mut rows = [[0.0; 4]; 4];
+    let mut found = 0;
+    let mut first_extra = None;
+    let mut rest = inner;
+    let mut rest_offset = offset;
+    loop {
+        let trimmed = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        rest_offset += rest.len() - trimmed.len();
+        rest = trimmed;
+
+        let first = match rest.chars().next() {
+            Some(c) => c,
+            None => break,
+        };
+        if !is_open(first) {
+            return Err(ParseVectorError::new(rest_offset, ParseVectorErrorKind::Bracket));
+        }
+
+        let close = matching_close(rest, rest_offset)?;
+        if found < 4 {
+            rows[found] = parse_components(&rest[1..close], rest_offset + 1)?;
+        } else if first_extra.is_none() {
+            first_extra = Some(rest_offset);
+        }
+        found += 1;
+
+        rest = &rest[close + 1..];
+        rest_offset += close + 1;
+    }
+
+    if found != 4 {
+        let position = first_extra.unwrap_or(rest_offset);
+        return Err(ParseVectorError::new(position, ParseVectorErrorKind::Count { expected: 4, found }));
+    }
+    Ok(rows)
+}
+
+
+// DISPLAY
+
+impl fmt::Display for Vector2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_components(f, '(', ')', self.as_array())
+    }
+}
+
+impl fmt::Display for Vector3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_components(f, '(', ')', self.as_array())
+    }
+}
+
+impl fmt::Display for Vector4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_components(f, '(', ')', self.as_array())
+    }
+}
+
+impl fmt::Display for Vector2i {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_components(f, '(', ')', self.as_array())
+    }
+}
+
+impl fmt::Display for Vector3i {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_components(f, '(', ')', self.as_array())
+    }
+}
+
+impl fmt::Display for Vector4i {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_components(f, '(', ')', self.as_array())
+    }
+}
+
+/// One bracketed row per line, in the usual mathematical layout rather than the column-major
+/// storage order
+impl fmt::Display for Matrix4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: [[f64; 4]; 4] = self.transpose().into();
+        for (i, row) in rows.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write_components(f, '[', ']', row)?;
+        }
+        Ok(())
+    }
+}
+
+
+// PARSING
+
+/// Parses `(x, y)`, `[x, y]` or `x y`
+impl FromStr for Vector2 {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_vector::<f64, 2>(s).map(Vector2::from)
+    }
+}
+
+/// Parses `(x, y, z)`, `[x, y, z]` or `x y z`
+impl FromStr for Vector3 {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_vector::<f64, 3>(s).map(Vector3::from)
+    }
+}
+
+/// Parses `(x, y, z, w)`, `[x, y, z, w]` or `x y z w`
+impl FromStr for Vector4 {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_vector::<f64, 4>(s).map(Vector4::from)
+    }
+}
+
+impl FromStr for Vector2i {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_vector::<i64, 2>(s).map(Vector2i::from)
+    }
+}
+
+impl FromStr for Vector3i {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_vector::<i64, 3>(s).map(Vector3i::from)
+    }
+}
+
+impl FromStr for Vector4i {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_vector::<i64, 4>(s).map(Vector4i::from)
+    }
+}
+
+/// Parses 16 numbers or 4 bracketed rows in row-major order, as printed by `Display`
+impl FromStr for Matrix4 {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_matrix(s).map(|rows| Matrix4::from(rows).transpose())
+    }
+}
+
+
+impl fmt::Display for ParseVectorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ParseVectorErrorKind::Number => write!(f, "invalid number")?,
+            ParseVectorErrorKind::Missing => write!(f, "missing component")?,
+            ParseVectorErrorKind::Count { expected, found } => {
+                write!(f, "expected {} components, found {}", expected, found)?
+            }
+            ParseVectorErrorKind::Bracket => write!(f, "unmatched or misplaced bracket")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl Error for ParseVectorError {}
@@ -0,0 +1,431 @@
+use std::f64::consts::PI;
+use std::ops::{Mul, MulAssign};
+use super::{Vector2, Vector3, Matrix4, Quaternion, Transform, Affine2};
+
+/// A rotation followed by a translation, preserving distances and angles
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Isometry3 {
+    pub rotation: Quaternion,
+    pub translation: Vector3,
+}
+
+
+/// A uniform scale and rotation followed by a translation, preserving angles
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Similarity3 {
+    pub scale: f64,
+    pub rotation: Quaternion,
+    pub translation: Vector3,
+}
+
+
+/// A 2D rotation followed by a translation, preserving distances and angles
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Isometry2 {
+    /// Counter-clockwise rotation in radians
+    pub rotation: f64,
+    pub translation: Vector2,
+}
+
+
+/// A 2D uniform scale and rotation followed by a translation, preserving angles
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Similarity2 {
+    pub scale: f64,
+
+    /// Counter-clockwise rotation in radians
+    pub rotation: f64,
+    pub translation: Vector2,
+}
+
+
+/// Interpolate between two angles in radians along the shortest arc
+fn lerp_angle(from: f64, to: f64, t: f64) -> f64 {
+    let delta = (to - from + PI).rem_euclid(2.0 * PI) - PI;
+    from + delta * t
+}
+
+
+impl Isometry3 {
+    /// Create a new isometry
+    pub fn new(rotation: Quaternion, translation: Vector3) -> Isometry3 {
+        Isometry3 { rotation, translation }
+    }
+
+    /// The identity isometry
+    pub fn identity() -> Isometry3 {
+        Isometry3::new(Quaternion::identity(), Vector3::new(0.0, 0.0, 0.0))
+    }
+
+    /// A pure translation
+    pub fn from_translation(translation: Vector3) -> Isometry3 {
+        Isometry3::new(Quaternion::identity(), translation)
+    }
+
+    /// A pure rotation
+    pub fn from_rotation(rotation: Quaternion) -> Isometry3 {
+        Isometry3::new(rotation, Vector3::new(0.0, 0.0, 0.0))
+    }
+
+
+    /// Transform a point
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        self.rotation.rotate(point) + self.translation
+    }
+
+    /// Transform a direction, ignoring translation
+    pub fn transform_vector(&self, vector: Vector3) -> Vector3 {
+        self.rotation.rotate(vector)
+    }
+
+
+    /// The inverse isometry
+    pub fn inverse(&self) -> Isometry3 {
+        let rotation = self.rotation.conjugate();
+        Isometry3 {
+            rotation,
+            translation: -rotation.rotate(self.translation),
+        }
+    }
+
+    /// Interpolate translation linearly and rotation spherically
+    pub fn lerp(&self, other: Isometry3, t: f64) -> Isometry3 {
+        Isometry3 {
+            rotation: self.rotation.slerp(other.rotation, t),
+            translation: self.translation + (other.translation - self.translation) * t,
+        }
+    }
+
+
+    /// The equivalent matrix
+    pub fn to_matrix(&self) -> Matrix4 {
+        Matrix4::translated(self.translation) * Matrix4::from(self.rotation)
+    }
+}
+
+
+impl Similarity3 {
+    /// Create a new similarity
+    pub fn new(scale: f64, rotation: Quaternion, translation: Vector3) -> Similarity3 {
+        Similarity3 { scale, rotation, translation }
+    }
+
+    /// The identity similarity
+    pub fn identity() -> Similarity3 {
+        Similarity3::new(1.0, Quaternion::identity(), Vector3::new(0.0, 0.0, 0.0))
+    }
+
+    /// A pure uniform scale
+    pub fn from_scale(scale: f64) -> Similarity3 {
+        Similarity3 { scale, ..Similarity3::identity() }
+    }
+
+
+    /// The rotation and translation, without scale
+    pub fn isometry(&self) -> Isometry3 {
+        Isometry3::new(self.rotation, self.translation)
+    }
+
+
+    /// Transform a point
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        self.rotation.rotate(point * self.scale) + self.translation
+    }
+
+    /// Transform a direction, ignoring translation
+    pub fn transform_vector(&self, vector: Vector3) -> Vector3 {
+        self.rotation.rotate(vector * self.scale)
+    }
+
+
+    /// The inverse similarity
+    pub fn inverse(&self) -> Similarity3 {
+        let rotation = self.rotation.conjugate();
+        let scale = 1.0 / self.scale;
+        Similarity3 {
+            scale,
+            rotation,
+            translation: -rotation.rotate(self.translation) * scale,
+        }
+    }
+
+    /// Interpolate translation and scale linearly and rotation spherically
+    pub fn lerp(&self, other: Similarity3, t: f64) -> Similarity3 {
+        Similarity3 {
+            scale: self.scale + (other.scale - self.scale) * t,
+            rotation: self.rotation.slerp(other.rotation, t),
+            translation: self.translation + (other.translation - self.translation) * t,
+        }
+    }
+
+
+    /// The equivalent matrix
+    pub fn to_matrix(&self) -> Matrix4 {
+        let scale = Vector3::new(self.scale, self.scale, self.scale);
+        Matrix4::translated(self.translation) * Matrix4::from(self.rotation) * Matrix4::scaled(scale)
+    }
+}
+
+
+impl Isometry2 {
+    /// Create a new isometry
+    pub fn new(rotation: f64, translation: Vector2) -> Isometry2 {
+        Isometry2 { rotation, translation }
+    }
+
+    /// The identity isometry
+    pub fn identity() -> Isometry2 {
+        Isometry2::new(0.0, Vector2::new(0.0, 0.0))
+    }
+
+
+    /// Transform a point
+    pub fn transform_point(&self, point: Vector2) -> Vector2 {
+        point.rotate(self.rotation) + self.translation
+    }
+
+    /// Transform a direction, ignoring translation
+    pub fn transform_vector(&self, vector: Vector2) -> Vector2 {
+        vector.rotate(self.rotation)
+    }
+
+
+    /// The inverse isometry
+    pub fn inverse(&self) -> Isometry2 {
+        Isometry2 {
+            rotation: -self.rotation,
+            translation: -self.translation.rotate(-self.rotation),
+        }
+    }
+
+    /// Interpolate translation linearly and rotation along the shortest arc
+    pub fn lerp(&self, other: Isometry2, t: f64) -> Isometry2 {
+        Isometry2 {
+            rotation: lerp_angle(self.rotation, other.rotation, t),
+            translation: self.translation + (other.translation - self.translation) * t,
+        }
+    }
+}
+
+
+impl Similarity2 {
+    /// Create a new similarity
+    pub fn new(scale: f64, rotation: f64, translation: Vector2) -> Similarity2 {
+        Similarity2 { scale, rotation, translation }
+    }
+
+    /// The identity similarity
+    pub fn identity() -> Similarity2 {
+        Similarity2::new(1.0, 0.0, Vector2::new(0.0, 0.0))
+    }
+
+
+    /// The rotation and translation, without scale
+    pub fn isometry(&self) -> Isometry2 {
+        Isometry2::new(self.rotation, self.translation)
+    }
+
+
+    /// Transform a point
+    pub fn transform_point(&self, point: Vector2) -> Vector2 {
+        (point * self.scale).rotate(self.rotation) + self.translation
+    }
+
+    /// Transform a direction, ignoring translation
+    pub fn transform_vector(&self, vector: Vector2) -> Vector2 {
+        (vector * self.scale).rotate(self.rotation)
+    }
+
+
+    /// The inverse similarity
+    pub fn inverse(&self) -> Similarity2 {
+        let scale = 1.0 / self.scale;
+        Similarity2 {
+            scale,
+            rotation: -self.rotation,
+            translation: -self.translation.rotate(-self.rotation) * scale,
+        }
+    }
+
+    /// Interpolate translation and scale linearly and rotation along the shortest arc
+    pub fn lerp(&self, other: Similarity2, t: f64) -> Similarity2 {
+        Similarity2 {
+            scale: self.scale + (other.scale - self.scale) * t,
+            rotation: lerp_angle(self.rotation, other.rotation, t),
+            translation: self.translation + (other.translation - self.translation) * t,
+        }
+    }
+}
+
+
+impl Default for Isometry3 {
+    fn default() -> Self {
+        Isometry3::identity()
+    }
+}
+
+impl Default for Similarity3 {
+    fn default() -> Self {
+        Similarity3::identity()
+    }
+}
+
+impl Default for Isometry2 {
+    fn default() -> Self {
+        Isometry2::identity()
+    }
+}
+
+impl Default for Similarity2 {
+    fn default() -> Self {
+        Similarity2::identity()
+    }
+}
+
+
+// MULTIPLICATION
+
+// Composition, `a * b` applies `b` first and then `a`
+impl Mul<Isometry3> for Isometry3 {
+    type Output = Isometry3;
+
+    fn mul(self, rhs: Isometry3) -> Self::Output {
+        Isometry3 {
+            rotation: self.rotation * rhs.rotation,
+            translation: self.transform_point(rhs.translation),
+        }
+    }
+}
+
+impl MulAssign<Isometry3> for Isometry3 {
+    fn mul_assign(&mut self, rhs: Isometry3) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Similarity3> for Similarity3 {
+    type Output = Similarity3;
+
+    fn mul(self, rhs: Similarity3) -> Self::Output {
+        Similarity3 {
+            scale: self.scale * rhs.scale,
+            rotation: self.rotation * rhs.rotation,
+            translation: self.transform_point(rhs.translation),
+        }
+    }
+}
+
+impl MulAssign<Similarity3> for Similarity3 {
+    fn mul_assign(&mut self, rhs: Similarity3) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Isometry2> for Isometry2 {
+    type Output = Isometry2;
+
+    // Rotation angles compose by addition
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Isometry2) -> Self::Output {
+        Isometry2 {
+            rotation: self.rotation + rhs.rotation,
+            translation: self.transform_point(rhs.translation),
+        }
+    }
+}
+
+impl MulAssign<Isometry2> for Isometry2 {
+    fn mul_assign(&mut self, rhs: Isometry2) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Similarity2> for Similarity2 {
+    type Output = Similarity2;
+
+    // Rotation angles compose by addition
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Similarity2) -> Self::Output {
+        Similarity2 {
+            scale: self.scale * rhs.scale,
+            rotation: self.rotation + rhs.rotation,
+            translation: self.transform_point(rhs.translation),
+        }
+    }
+}
+
+impl MulAssign<Similarity2> for Similarity2 {
+    fn mul_assign(&mut self, rhs: Similarity2) {
+        *self = *self * rhs;
+    }
+}
+
+
+// CONVERSION
+
+impl From<Isometry3> for Similarity3 {
+    fn from(isometry: Isometry3) -> Self {
+        Similarity3::new(1.0, isometry.rotation, isometry.translation)
+    }
+}
+
+impl From<Isometry3> for Transform {
+    fn from(isometry: Isometry3) -> Self {
+        Transform::new(isometry.translation, isometry.rotation, Vector3::new(1.0, 1.0, 1.0))
+    }
+}
+
+impl From<Similarity3> for Transform {
+    fn from(similarity: Similarity3) -> Self {
+        let scale = Vector3::new(similarity.scale, similarity.scale, similarity.scale);
+        Transform::new(similarity.translation, similarity.rotation, scale)
+    }
+}
+
+impl From<Isometry3> for Matrix4 {
+    fn from(isometry: Isometry3) -> Self {
+        isometry.to_matrix()
+    }
+}
+
+impl From<Similarity3> for Matrix4 {
+    fn from(similarity: Similarity3) -> Self {
+        similarity.to_matrix()
+    }
+}
+
+impl From<Isometry2> for Similarity2 {
+    fn from(isometry: Isometry2) -> Self {
+        Similarity2::new(1.0, isometry.rotation, isometry.translation)
+    }
+}
+
+impl From<Isometry2> for Affine2 {
+    fn from(isometry: Isometry2) -> Self {
+        Affine2::translated(isometry.translation).rotate(isometry.rotation)
+    }
+}
+
+impl From<Similarity2> for Affine2 {
+    fn from(similarity: Similarity2) -> Self {
+        Affine2::translated(similarity.translation)
+            .rotate(similarity.rotation)
+            .scale(Vector2::new(similarity.scale, similarity.scale))
+    }
+}
+
+impl From<Isometry2> for Matrix4 {
+    fn from(isometry: Isometry2) -> Self {
+        Affine2::from(isometry).into()
+    }
+}
+
+impl From<Similarity2> for Matrix4 {
+    fn from(similarity: Similarity2) -> Self {
+        Affine2::from(similarity).into()
+    }
+}
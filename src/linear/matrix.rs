@@ -8,15 +8,19 @@ mod matrix4 {
     use super::super::{
         Vector3,
         Vector4,
+        Unit,
     };
     use vecmath::{
         col_mat4_mul,
         mat4_id,
         col_mat4_transform,
         mat4_transposed,
+        mat4_det,
+        mat4_inv,
     };
 
-    #[derive(Copy, Clone)]
+    #[derive(Copy, Clone, PartialEq)]
+    #[derive(Debug)]
     pub struct Matrix4 {
         data: [[f64; 4]; 4]
     }
@@ -78,6 +82,21 @@ mod matrix4 {
         pub fn look_at(eye: Vector3, target: Vector3, up: Vector3) -> Matrix4 {
             let forward = (eye - target).normal();
             let right = up.cross(forward).normal();
+
+            Matrix4::view(eye, right, forward)
+        }
+
+        /// Like `look_at`, but returns `None` instead of NaNs if `eye` and `target`
+        /// coincide or `up` is parallel to the view direction
+        pub fn try_look_at(eye: Vector3, target: Vector3, up: Vector3) -> Option<Matrix4> {
+            let forward = Unit::new(eye - target)?;
+            let right = Unit::new(up.cross(*forward))?;
+
+            Some(Matrix4::view(eye, *right, *forward))
+        }
+
+        /// View matrix from an eye position and the orthonormal right and forward axes of `look_at`
+        fn view(eye: Vector3, right: Vector3, forward: Vector3) -> Matrix4 {
             let up = forward.cross(right);
 
             Matrix4 {
@@ -91,6 +110,23 @@ mod matrix4 {
         }
 
 
+        /// Rotation of `angle` radians counter-clockwise around an axis
+        pub fn rotated(axis: Unit<Vector3>, angle: f64) -> Matrix4 {
+            let Vector3 { x, y, z } = *axis;
+            let (s, c) = angle.sin_cos();
+            let t = 1.0 - c;
+
+            Matrix4 {
+                data: [
+                    [c + x * x * t,     x * y * t + z * s, x * z * t - y * s, 0.0],
+                    [x * y * t - z * s, c + y * y * t,     y * z * t + x * s, 0.0],
+                    [x * z * t + y * s, y * z * t - x * s, c + z * z * t,     0.0],
+                    [0.0,               0.0,               0.0,               1.0],
+                ],
+            }
+        }
+
+
         pub fn translate(self, amount: Vector3) -> Matrix4 {
             let translation = Matrix4::translated(amount);
             self * translation
@@ -101,6 +137,11 @@ mod matrix4 {
             self * scaling
         }
 
+        pub fn rotate(self, axis: Unit<Vector3>, angle: f64) -> Matrix4 {
+            let rotation = Matrix4::rotated(axis, angle);
+            self * rotation
+        }
+
 
         /// Return the transpose of a matrix
         pub fn transpose(self) -> Matrix4 {
@@ -108,6 +149,29 @@ mod matrix4 {
                 data: mat4_transposed(self.data)
             }
         }
+
+
+        /// Return the determinant of a matrix
+        pub fn determinant(self) -> f64 {
+            mat4_det(self.data)
+        }
+
+        /// Return the inverse of a matrix, or `None` if it is singular
+        pub fn inverse(self) -> Option<Matrix4> {
+            let determinant = self.determinant();
+            if determinant == 0.0 || !determinant.is_finite() {
+                None
+            } else {
+                Some(Matrix4 { data: mat4_inv(self.data) })
+            }
+        }
+    }
+
+
+    impl Default for Matrix4 {
+        fn default() -> Self {
+            Matrix4::new()
+        }
     }
 
 
@@ -1,5 +1,9 @@
 
 //! Friendlier wrappers around the vecmath crate
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
 mod vector;
 pub use self::vector::{
     Vector2,
@@ -14,9 +18,45 @@ pub use self::vectori::{
     Vector4i
 };
 
+mod axis;
+pub use self::axis::{Axis2, Axis3, Axis};
+
+mod format;
+pub use self::format::{ParseVectorError, ParseVectorErrorKind};
+
 mod matrix;
 pub use self::matrix::Matrix4;
 
+mod unit;
+pub use self::unit::{Unit, Normalize};
+
+mod ordered;
+pub use self::ordered::{Ordered, TotalOrder};
+
+mod quaternion;
+pub use self::quaternion::Quaternion;
+
+mod transform;
+pub use self::transform::{Transform, DecomposeError};
+
+mod affine;
+pub use self::affine::Affine2;
+
+mod isometry;
+pub use self::isometry::{Isometry3, Similarity3, Isometry2, Similarity2};
+
+mod dual_quaternion;
+pub use self::dual_quaternion::DualQuaternion;
+
+mod euler;
+pub use self::euler::{EulerAngles, EulerOrder};
+
+mod aabb;
+pub use self::aabb::{Aabb2, Aabb3};
+
+mod spherical;
+pub use self::spherical::{Spherical, Cylindrical, UpAxis};
+
 
 
 // Conversion between vector types
@@ -80,4 +120,549 @@ impl From<Vector4> for Vector4i {
             w: v.w as i64,
         }
     }
-}
\ No newline at end of file
+}
+
+
+// Extension and truncation between dimensions
+
+impl Vector2 {
+    /// Append a z component
+    pub fn extend(&self, z: f64) -> Vector3 {
+        Vector3::new(self.x, self.y, z)
+    }
+
+    /// Homogeneous coordinates of a point, with z = 1 so that translations apply
+    pub fn to_homogeneous_point(&self) -> Vector3 {
+        self.extend(1.0)
+    }
+
+    /// Homogeneous coordinates of a direction, with z = 0 so that translations do not apply
+    pub fn to_homogeneous_vector(&self) -> Vector3 {
+        self.extend(0.0)
+    }
+
+    /// Divide homogeneous coordinates by z, or `None` if z is zero and the point is at infinity
+    pub fn from_homogeneous(v: Vector3) -> Option<Vector2> {
+        if v.z == 0.0 {
+            None
+        } else {
+            Some(v.truncate() / v.z)
+        }
+    }
+}
+
+impl Vector3 {
+    /// Append a w component
+    pub fn extend(&self, w: f64) -> Vector4 {
+        Vector4::new(self.x, self.y, self.z, w)
+    }
+
+    /// Drop the z component
+    pub fn truncate(&self) -> Vector2 {
+        Vector2::new(self.x, self.y)
+    }
+
+    /// Homogeneous coordinates of a point, with w = 1 so that translations apply
+    pub fn to_homogeneous_point(&self) -> Vector4 {
+        self.extend(1.0)
+    }
+
+    /// Homogeneous coordinates of a direction, with w = 0 so that translations do not apply
+    pub fn to_homogeneous_vector(&self) -> Vector4 {
+        self.extend(0.0)
+    }
+
+    /// Divide homogeneous coordinates by w, or `None` if w is zero and the point is at infinity
+    pub fn from_homogeneous(v: Vector4) -> Option<Vector3> {
+        if v.w == 0.0 {
+            None
+        } else {
+            Some(v.truncate() / v.w)
+        }
+    }
+}
+
+impl Vector4 {
+    /// Drop the w component
+    pub fn truncate(&self) -> Vector3 {
+        Vector3::new(self.x, self.y, self.z)
+    }
+}
+
+
+impl Vector2i {
+    /// Append a z component
+    pub fn extend(&self, z: i64) -> Vector3i {
+        Vector3i::new(self.x, self.y, z)
+    }
+
+    /// Homogeneous coordinates of a point, with z = 1
+    pub fn to_homogeneous_point(&self) -> Vector3i {
+        self.extend(1)
+    }
+
+    /// Homogeneous coordinates of a direction, with z = 0
+    pub fn to_homogeneous_vector(&self) -> Vector3i {
+        self.extend(0)
+    }
+
+    /// Divide homogeneous coordinates by z, or `None` if z is zero, does not divide every
+    /// component exactly or the quotient overflows
+    pub fn from_homogeneous(v: Vector3i) -> Option<Vector2i> {
+        let divide = |component: i64| match component.checked_rem(v.z)? {
+            0 => component.checked_div(v.z),
+            _ => None,
+        };
+        Some(Vector2i::new(divide(v.x)?, divide(v.y)?))
+    }
+}
+
+impl Vector3i {
+    /// Append a w component
+    pub fn extend(&self, w: i64) -> Vector4i {
+        Vector4i::new(self.x, self.y, self.z, w)
+    }
+
+    /// Drop the z component
+    pub fn truncate(&self) -> Vector2i {
+        Vector2i::new(self.x, self.y)
+    }
+
+    /// Homogeneous coordinates of a point, with w = 1
+    pub fn to_homogeneous_point(&self) -> Vector4i {
+        self.extend(1)
+    }
+
+    /// Homogeneous coordinates of a direction, with w = 0
+    pub fn to_homogeneous_vector(&self) -> Vector4i {
+        self.extend(0)
+    }
+
+    /// Divide homogeneous coordinates by w, or `None` if w is zero, does not divide every
+    /// component exactly or the quotient overflows
+    pub fn from_homogeneous(v: Vector4i) -> Option<Vector3i> {
+        let divide = |component: i64| match component.checked_rem(v.w)? {
+            0 => component.checked_div(v.w),
+            _ => None,
+        };
+        Some(Vector3i::new(divide(v.x)?, divide(v.y)?, divide(v.z)?))
+    }
+}
+
+impl Vector4i {
+    /// Drop the w component
+    pub fn truncate(&self) -> Vector3i {
+        Vector3i::new(self.x, self.y, self.z)
+    }
+}
+
+
+// Checked conversion between float and integer vectors, and to narrower integers
+
+/// An error from converting a vector to one with a narrower component type
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+pub enum TryFromVectorError {
+    /// A component is NaN
+    NaN,
+
+    /// A component is infinite or does not fit in the target type
+    OutOfRange,
+}
+
+
+/// Truncate a float to an integer, unless it is NaN or out of range
+fn checked_i64(x: f64) -> Result<i64, TryFromVectorError> {
+    // 2^63, exactly representable as a float
+    const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+
+    let truncated = x.trunc();
+    if x.is_nan() {
+        Err(TryFromVectorError::NaN)
+    } else if !(-LIMIT..LIMIT).contains(&truncated) {
+        Err(TryFromVectorError::OutOfRange)
+    } else {
+        Ok(truncated as i64)
+    }
+}
+
+/// Convert to a narrower integer, unless it is out of range
+fn narrow<T: TryFrom<i64>>(x: i64) -> Result<T, TryFromVectorError> {
+    T::try_from(x).map_err(|_| TryFromVectorError::OutOfRange)
+}
+
+
+impl Vector2i {
+    /// Round the components of a float vector down.
+    ///
+    /// Like `as`, the rounding conversions map NaN to zero and saturate out of range values.
+    pub fn floor(v: Vector2) -> Vector2i {
+        Vector2i {
+            x: v.x.floor() as i64,
+            y: v.y.floor() as i64,
+        }
+    }
+
+    /// Round the components of a float vector to the nearest integer, with halves away from zero
+    pub fn round(v: Vector2) -> Vector2i {
+        Vector2i {
+            x: v.x.round() as i64,
+            y: v.y.round() as i64,
+        }
+    }
+
+    /// Round the components of a float vector up
+    pub fn ceil(v: Vector2) -> Vector2i {
+        Vector2i {
+            x: v.x.ceil() as i64,
+            y: v.y.ceil() as i64,
+        }
+    }
+
+    /// Round the components of a float vector toward zero, the same as `From`
+    pub fn trunc(v: Vector2) -> Vector2i {
+        Vector2i {
+            x: v.x.trunc() as i64,
+            y: v.y.trunc() as i64,
+        }
+    }
+
+    /// Truncate toward zero like `trunc`, or fail if a component is NaN or out of range
+    pub fn try_from_float(v: Vector2) -> Result<Vector2i, TryFromVectorError> {
+        Ok(Vector2i {
+            x: checked_i64(v.x)?,
+            y: checked_i64(v.y)?,
+        })
+    }
+}
+
+impl From<[i32; 2]> for Vector2i {
+    fn from(array: [i32; 2]) -> Self {
+        Vector2i {
+            x: array[0] as i64,
+            y: array[1] as i64,
+        }
+    }
+}
+
+impl TryFrom<Vector2i> for [i32; 2] {
+    type Error = TryFromVectorError;
+
+    fn try_from(v: Vector2i) -> Result<Self, Self::Error> {
+        Ok([narrow(v.x)?, narrow(v.y)?])
+    }
+}
+
+impl From<[u32; 2]> for Vector2i {
+    fn from(array: [u32; 2]) -> Self {
+        Vector2i {
+            x: array[0] as i64,
+            y: array[1] as i64,
+        }
+    }
+}
+
+impl TryFrom<Vector2i> for [u32; 2] {
+    type Error = TryFromVectorError;
+
+    fn try_from(v: Vector2i) -> Result<Self, Self::Error> {
+        Ok([narrow(v.x)?, narrow(v.y)?])
+    }
+}
+
+impl From<[u16; 2]> for Vector2i {
+    fn from(array: [u16; 2]) -> Self {
+        Vector2i {
+            x: array[0] as i64,
+            y: array[1] as i64,
+        }
+    }
+}
+
+impl TryFrom<Vector2i> for [u16; 2] {
+    type Error = TryFromVectorError;
+
+    fn try_from(v: Vector2i) -> Result<Self, Self::Error> {
+        Ok([narrow(v.x)?, narrow(v.y)?])
+    }
+}
+
+impl From<[u8; 2]> for Vector2i {
+    fn from(array: [u8; 2]) -> Self {
+        Vector2i {
+            x: array[0] as i64,
+            y: array[1] as i64,
+        }
+    }
+}
+
+impl TryFrom<Vector2i> for [u8; 2] {
+    type Error = TryFromVectorError;
+
+    fn try_from(v: Vector2i) -> Result<Self, Self::Error> {
+        Ok([narrow(v.x)?, narrow(v.y)?])
+    }
+}
+
+impl Vector3i {
+    /// Round the components of a float vector down.
+    ///
+    /// Like `as`, the rounding conversions map NaN to zero and saturate out of range values.
+    pub fn floor(v: Vector3) -> Vector3i {
+        Vector3i {
+            x: v.x.floor() as i64,
+            y: v.y.floor() as i64,
+            z: v.z.floor() as i64,
+        }
+    }
+
+    /// Round the components of a float vector to the nearest integer, with halves away from zero
+    pub fn round(v: Vector3) -> Vector3i {
+        Vector3i {
+            x: v.x.round() as i64,
+            y: v.y.round() as i64,
+            z: v.z.round() as i64,
+        }
+    }
+
+    /// Round the components of a float vector up
+    pub fn ceil(v: Vector3) -> Vector3i {
+        Vector3i {
+            x: v.x.ceil() as i64,
+            y: v.y.ceil() as i64,
+            z: v.z.ceil() as i64,
+        }
+    }
+
+    /// Round the components of a float vector toward zero, the same as `From`
+    pub fn trunc(v: Vector3) -> Vector3i {
+        Vector3i {
+            x: v.x.trunc() as i64,
+            y: v.y.trunc() as i64,
+            z: v.z.trunc() as i64,
+        }
+    }
+
+    /// Truncate toward zero like `trunc`, or fail if a component is NaN or out of range
+    pub fn try_from_float(v: Vector3) -> Result<Vector3i, TryFromVectorError> {
+        Ok(Vector3i {
+            x: checked_i64(v.x)?,
+            y: checked_i64(v.y)?,
+            z: checked_i64(v.z)?,
+        })
+    }
+}
+
+impl From<[i32; 3]> for Vector3i {
+    fn from(array: [i32; 3]) -> Self {
+        Vector3i {
+            x: array[0] as i64,
+            y: array[1] as i64,
+            z: array[2] as i64,
+        }
+    }
+}
+
+impl TryFrom<Vector3i> for [i32; 3] {
+    type Error = TryFromVectorError;
+
+    fn try_from(v: Vector3i) -> Result<Self, Self::Error> {
+        Ok([narrow(v.x)?, narrow(v.y)?, narrow(v.z)?])
+    }
+}
+
+impl From<[u32; 3]> for Vector3i {
+    fn from(array: [u32; 3]) -> Self {
+        Vector3i {
+            x: array[0] as i64,
+            y: array[1] as i64,
+            z: array[2] as i64,
+        }
+    }
+}
+
+impl TryFrom<Vector3i> for [u32; 3] {
+    type Error = TryFromVectorError;
+
+    fn try_from(v: Vector3i) -> Result<Self, Self::Error> {
+        Ok([narrow(v.x)?, narrow(v.y)?, narrow(v.z)?])
+    }
+}
+
+impl From<[u16; 3]> for Vector3i {
+    fn from(array: [u16; 3]) -> Self {
+        Vector3i {
+            x: array[0] as i64,
+            y: array[1] as i64,
+            z: array[2] as i64,
+        }
+    }
+}
+
+impl TryFrom<Vector3i> for [u16; 3] {
+    type Error = TryFromVectorError;
+
+    fn try_from(v: Vector3i) -> Result<Self, Self::Error> {
+        Ok([narrow(v.x)?, narrow(v.y)?, narrow(v.z)?])
+    }
+}
+
+impl From<[u8; 3]> for Vector3i {
+    fn from(array: [u8; 3]) -> Self {
+        Vector3i {
+            x: array[0] as i64,
+            y: array[1] as i64,
+            z: array[2] as i64,
+        }
+    }
+}
+
+impl TryFrom<Vector3i> for [u8; 3] {
+    type Error = TryFromVectorError;
+
+    fn try_from(v: Vector3i) -> Result<Self, Self::Error> {
+        Ok([narrow(v.x)?, narrow(v.y)?, narrow(v.z)?])
+    }
+}
+
+impl Vector4i {
+    /// Round the components of a float vector down.
+    ///
+    /// Like `as`, the rounding conversions map NaN to zero and saturate out of range values.
+    pub fn floor(v: Vector4) -> Vector4i {
+        Vector4i {
+            x: v.x.floor() as i64,
+            y: v.y.floor() as i64,
+            z: v.z.floor() as i64,
+            w: v.w.floor() as i64,
+        }
+    }
+
+    /// Round the components of a float vector to the nearest integer, with halves away from zero
+    pub fn round(v: Vector4) -> Vector4i {
+        Vector4i {
+            x: v.x.round() as i64,
+            y: v.y.round() as i64,
+            z: v.z.round() as i64,
+            w: v.w.round() as i64,
+        }
+    }
+
+    /// Round the components of a float vector up
+    pub fn ceil(v: Vector4) -> Vector4i {
+        Vector4i {
+            x: v.x.ceil() as i64,
+            y: v.y.ceil() as i64,
+            z: v.z.ceil() as i64,
+            w: v.w.ceil() as i64,
+        }
+    }
+
+    /// Round the components of a float vector toward zero, the same as `From`
+    pub fn trunc(v: Vector4) -> Vector4i {
+        Vector4i {
+            x: v.x.trunc() as i64,
+            y: v.y.trunc() as i64,
+            z: v.z.trunc() as i64,
+            w: v.w.trunc() as i64,
+        }
+    }
+
+    /// Truncate toward zero like `trunc`, or fail if a component is NaN or out of range
+    pub fn try_from_float(v: Vector4) -> Result<Vector4i, TryFromVectorError> {
+        Ok(Vector4i {
+            x: checked_i64(v.x)?,
+            y: checked_i64(v.y)?,
+            z: checked_i64(v.z)?,
+            w: checked_i64(v.w)?,
+        })
+    }
+}
+
+impl From<[i32; 4]> for Vector4i {
+    fn from(array: [i32; 4]) -> Self {
+        Vector4i {
+            x: array[0] as i64,
+            y: array[1] as i64,
+            z: array[2] as i64,
+            w: array[3] as i64,
+        }
+    }
+}
+
+impl TryFrom<Vector4i> for [i32; 4] {
+    type Error = TryFromVectorError;
+
+    fn try_from(v: Vector4i) -> Result<Self, Self::Error> {
+        Ok([narrow(v.x)?, narrow(v.y)?, narrow(v.z)?, narrow(v.w)?])
+    }
+}
+
+impl From<[u32; 4]> for Vector4i {
+    fn from(array: [u32; 4]) -> Self {
+        Vector4i {
+            x: array[0] as i64,
+            y: array[1] as i64,
+            z: array[2] as i64,
+            w: array[3] as i64,
+        }
+    }
+}
+
+impl TryFrom<Vector4i> for [u32; 4] {
+    type Error = TryFromVectorError;
+
+    fn try_from(v: Vector4i) -> Result<Self, Self::Error> {
+        Ok([narrow(v.x)?, narrow(v.y)?, narrow(v.z)?, narrow(v.w)?])
+    }
+}
+
+impl From<[u16; 4]> for Vector4i {
+    fn from(array: [u16; 4]) -> Self {
+        Vector4i {
+            x: array[0] as i64,
+            y: array[1] as i64,
+            z: array[2] as i64,
+            w: array[3] as i64,
+        }
+    }
+}
+
+impl TryFrom<Vector4i> for [u16; 4] {
+    type Error = TryFromVectorError;
+
+    fn try_from(v: Vector4i) -> Result<Self, Self::Error> {
+        Ok([narrow(v.x)?, narrow(v.y)?, narrow(v.z)?, narrow(v.w)?])
+    }
+}
+
+impl From<[u8; 4]> for Vector4i {
+    fn from(array: [u8; 4]) -> Self {
+        Vector4i {
+            x: array[0] as i64,
+            y: array[1] as i64,
+            z: array[2] as i64,
+            w: array[3] as i64,
+        }
+    }
+}
+
+impl TryFrom<Vector4i> for [u8; 4] {
+    type Error = TryFromVectorError;
+
+    fn try_from(v: Vector4i) -> Result<Self, Self::Error> {
+        Ok([narrow(v.x)?, narrow(v.y)?, narrow(v.z)?, narrow(v.w)?])
+    }
+}
+
+
+impl fmt::Display for TryFromVectorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TryFromVectorError::NaN => write!(f, "component is NaN"),
+            TryFromVectorError::OutOfRange => write!(f, "component is out of range"),
+        }
+    }
+}
+
+impl Error for TryFromVectorError {}
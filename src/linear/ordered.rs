@@ -0,0 +1,168 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use super::{Vector2, Vector3, Vector4};
+
+/// Float vectors with a total order over their components
+pub trait TotalOrder: Copy {
+    /// Replace `-0.0` with `0.0` and every NaN with the same NaN
+    fn canonicalize(&self) -> Self;
+
+    /// Compare lexicographically by the IEEE 754 total order of the components
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Feed the bits of the components to a hasher
+    fn hash_bits<H: Hasher>(&self, state: &mut H);
+}
+
+
+/// A float vector that is `Eq`, `Ord` and `Hash`, for use as a map key.
+///
+/// Zeroes and NaNs are canonicalized on construction, so `-0.0` equals `0.0` and all NaNs equal
+/// each other. Otherwise vectors are equal only if their components are identical; use
+/// `quantize` to merge nearly equal points.
+#[derive(Copy, Clone)]
+#[derive(Debug)]
+pub struct Ordered<T> {
+    value: T,
+}
+
+
+impl<T: TotalOrder> Ordered<T> {
+    /// Wrap a vector, canonicalizing its zeroes and NaNs
+    pub fn new(vector: T) -> Ordered<T> {
+        Ordered { value: vector.canonicalize() }
+    }
+}
+
+
+impl<T> Ordered<T> {
+    /// Unwrap the underlying vector
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+
+fn canonical(component: f64) -> f64 {
+    if component == 0.0 {
+        0.0
+    } else if component.is_nan() {
+        f64::NAN
+    } else {
+        component
+    }
+}
+
+impl TotalOrder for Vector2 {
+    fn canonicalize(&self) -> Vector2 {
+        Vector2::new(canonical(self.x), canonical(self.y))
+    }
+
+    fn total_cmp(&self, other: &Vector2) -> Ordering {
+        Vector2::total_cmp(self, other)
+    }
+
+    fn hash_bits<H: Hasher>(&self, state: &mut H) {
+        self.iter().for_each(|component| component.to_bits().hash(state));
+    }
+}
+
+impl TotalOrder for Vector3 {
+    fn canonicalize(&self) -> Vector3 {
+        Vector3::new(canonical(self.x), canonical(self.y), canonical(self.z))
+    }
+
+    fn total_cmp(&self, other: &Vector3) -> Ordering {
+        Vector3::total_cmp(self, other)
+    }
+
+    fn hash_bits<H: Hasher>(&self, state: &mut H) {
+        self.iter().for_each(|component| component.to_bits().hash(state));
+    }
+}
+
+impl TotalOrder for Vector4 {
+    fn canonicalize(&self) -> Vector4 {
+        Vector4::new(canonical(self.x), canonical(self.y), canonical(self.z), canonical(self.w))
+    }
+
+    fn total_cmp(&self, other: &Vector4) -> Ordering {
+        Vector4::total_cmp(self, other)
+    }
+
+    fn hash_bits<H: Hasher>(&self, state: &mut H) {
+        self.iter().for_each(|component| component.to_bits().hash(state));
+    }
+}
+
+
+// COMPARISON
+
+impl<T: TotalOrder> PartialEq for Ordered<T> {
+    fn eq(&self, other: &Ordered<T>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: TotalOrder> Eq for Ordered<T> {}
+
+impl<T: TotalOrder> PartialOrd for Ordered<T> {
+    fn partial_cmp(&self, other: &Ordered<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: TotalOrder> Ord for Ordered<T> {
+    fn cmp(&self, other: &Ordered<T>) -> Ordering {
+        self.value.total_cmp(&other.value)
+    }
+}
+
+impl<T: TotalOrder> Hash for Ordered<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash_bits(state);
+    }
+}
+
+
+impl<T> Deref for Ordered<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> AsRef<T> for Ordered<T> {
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+
+// CONVERSION
+
+impl<T: TotalOrder> From<T> for Ordered<T> {
+    fn from(vector: T) -> Self {
+        Ordered::new(vector)
+    }
+}
+
+impl From<Ordered<Vector2>> for Vector2 {
+    fn from(ordered: Ordered<Vector2>) -> Self {
+        ordered.value
+    }
+}
+
+impl From<Ordered<Vector3>> for Vector3 {
+    fn from(ordered: Ordered<Vector3>) -> Self {
+        ordered.value
+    }
+}
+
+impl From<Ordered<Vector4>> for Vector4 {
+    fn from(ordered: Ordered<Vector4>) -> Self {
+        ordered.value
+    }
+}
@@ -0,0 +1,368 @@
+use std::ops::{
+    Add,
+    Sub,
+    Mul, MulAssign,
+    Neg,
+};
+use super::{Vector3, Vector4, Matrix4, Unit};
+
+/// A quaternion `w + xi + yj + zk`, used to represent rotations.
+///
+/// Rotation methods expect quaternions of unit length.
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Quaternion {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+
+impl Quaternion {
+    /// Create a new quaternion
+    pub fn new(x: f64, y: f64, z: f64, w: f64) -> Quaternion {
+        Quaternion { x, y, z, w }
+    }
+
+    /// The identity rotation
+    pub fn identity() -> Quaternion {
+        Quaternion { x: 0.0, y: 0.0, z: 0.0, w: 1.0 }
+    }
+
+
+    /// Rotation of `angle` radians counter-clockwise around an axis
+    pub fn from_axis_angle(axis: Unit<Vector3>, angle: f64) -> Quaternion {
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        Quaternion {
+            x: axis.x * sin,
+            y: axis.y * sin,
+            z: axis.z * sin,
+            w: cos,
+        }
+    }
+
+    /// The rotation of a matrix whose upper 3x3 part is a pure rotation
+    pub fn from_matrix(matrix: Matrix4) -> Quaternion {
+        let m: [[f64; 4]; 4] = matrix.into();
+
+        // m is stored column-major, so m[col][row]
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quaternion {
+                x: (m[1][2] - m[2][1]) / s,
+                y: (m[2][0] - m[0][2]) / s,
+                z: (m[0][1] - m[1][0]) / s,
+                w: s / 4.0,
+            }
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt() * 2.0;
+            Quaternion {
+                x: s / 4.0,
+                y: (m[1][0] + m[0][1]) / s,
+                z: (m[2][0] + m[0][2]) / s,
+                w: (m[1][2] - m[2][1]) / s,
+            }
+        } else if m[1][1] > m[2][2] {
+            let s = (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt() * 2.0;
+            Quaternion {
+                x: (m[1][0] + m[0][1]) / s,
+                y: s / 4.0,
+                z: (m[2][1] + m[1][2]) / s,
+                w: (m[2][0] - m[0][2]) / s,
+            }
+        } else {
+            let s = (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt() * 2.0;
+            Quaternion {
+                x: (m[2][0] + m[0][2]) / s,
+                y: (m[2][1] + m[1][2]) / s,
+                z: s / 4.0,
+                w: (m[0][1] - m[1][0]) / s,
+            }
+        }
+    }
+
+
+    /// The vector part
+    pub fn vector(&self) -> Vector3 {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+
+    /// Dot product
+    pub fn dot(&self, other: Quaternion) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Length of quaternion
+    pub fn len(&self) -> f64 {
+        self.dot(*self).sqrt()
+    }
+
+    /// Normalized unit quaternion
+    pub fn normal(&self) -> Quaternion {
+        *self * (1.0 / self.len())
+    }
+
+
+    /// The conjugate, which is the inverse rotation for unit quaternions
+    pub fn conjugate(&self) -> Quaternion {
+        Quaternion { x: -self.x, y: -self.y, z: -self.z, w: self.w }
+    }
+
+    /// The multiplicative inverse
+    pub fn inverse(&self) -> Quaternion {
+        self.conjugate() * (1.0 / self.dot(*self))
+    }
+
+
+    /// Rotate a vector
+    pub fn rotate(&self, v: Vector3) -> Vector3 {
+        let u = self.vector();
+        let t = 2.0 * u.cross(v);
+        v + self.w * t + u.cross(t)
+    }
+
+
+    /// Normalized linear interpolation along the shortest path
+    pub fn nlerp(&self, other: Quaternion, t: f64) -> Quaternion {
+        let other = if self.dot(other) < 0.0 { -other } else { other };
+        (*self * (1.0 - t) + other * t).normal()
+    }
+
+    /// Spherical linear interpolation along the shortest path
+    pub fn slerp(&self, other: Quaternion, t: f64) -> Quaternion {
+        let mut cos = self.dot(other);
+        let other = if cos < 0.0 {
+            cos = -cos;
+            -other
+        } else {
+            other
+        };
+
+        // Nearly identical rotations, avoid dividing by a vanishing sine
+        if cos > 0.9995 {
+            return self.nlerp(other, t);
+        }
+
+        let angle = cos.acos();
+        let sin = angle.sin();
+        *self * (((1.0 - t) * angle).sin() / sin) + other * ((t * angle).sin() / sin)
+    }
+
+
+    /// Shortest-arc rotation taking the direction of `from` to the direction of `to`, or `None`
+    /// if either is zero.
+    ///
+    /// Opposite directions are rotated by half a turn around an arbitrary orthogonal axis.
+    pub fn rotation_between(from: Vector3, to: Vector3) -> Option<Quaternion> {
+        let from = from.try_normal()?;
+        let to = to.try_normal()?;
+
+        let cos = from.dot(to);
+        if cos < -1.0 + 1e-12 {
+            let axis = from.any_orthogonal();
+            return Some(Quaternion::new(axis.x, axis.y, axis.z, 0.0));
+        }
+
+        // (sin(a) n, 1 + cos(a)) is proportional to the half angle quaternion (sin(a/2) n, cos(a/2))
+        let axis = from.cross(to);
+        Some(Quaternion::new(axis.x, axis.y, axis.z, 1.0 + cos).normal())
+    }
+
+
+    /// The exponential map, rotation of `|v|` radians counter-clockwise around `v`
+    pub fn from_rotation_vector(v: Vector3) -> Quaternion {
+        let angle = v.len();
+
+        // Taylor expansion of sin(angle / 2) / angle near zero
+        if angle < 1e-8 {
+            let half = v * 0.5;
+            return Quaternion::new(half.x, half.y, half.z, 1.0).normal();
+        }
+
+        let axis = v / angle;
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        Quaternion::new(axis.x * sin, axis.y * sin, axis.z * sin, cos)
+    }
+
+    /// The logarithmic map, axis times angle of a unit quaternion with the angle in `[0, pi]`
+    pub fn to_rotation_vector(&self) -> Vector3 {
+        // q and -q are the same rotation, pick the one turning less than half a turn
+        let q = if self.w < 0.0 { -*self } else { *self };
+
+        let v = q.vector();
+        let sin = v.len();
+        if sin < 1e-8 {
+            return v * 2.0;
+        }
+
+        v * (2.0 * sin.atan2(q.w) / sin)
+    }
+
+
+    /// Apply an angular velocity in radians per second, given in world space, over a timestep
+    pub fn integrate(&self, angular_velocity: Vector3, dt: f64) -> Quaternion {
+        (Quaternion::from_rotation_vector(angular_velocity * dt) * *self).normal()
+    }
+
+
+    /// Split into a swing and a twist around an axis, such that `self == swing * twist`.
+    ///
+    /// The twist rotates around the axis, the swing around an axis orthogonal to it. When the
+    /// rotation turns the axis by half a turn, the twist is ambiguous and the identity is used.
+    pub fn swing_twist(&self, axis: Unit<Vector3>) -> (Quaternion, Quaternion) {
+        let projected = *axis * self.vector().dot(*axis);
+        let twist = Quaternion::new(projected.x, projected.y, projected.z, self.w);
+
+        let len = twist.len();
+        let twist = if len < 1e-12 { Quaternion::identity() } else { twist * (1.0 / len) };
+
+        (*self * twist.conjugate(), twist)
+    }
+}
+
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Quaternion::identity()
+    }
+}
+
+
+// ADDITION
+
+impl Add<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, rhs: Quaternion) -> Self::Output {
+        Quaternion {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+            w: self.w + rhs.w,
+        }
+    }
+}
+
+
+// SUBTRACTION
+
+impl Sub<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn sub(self, rhs: Quaternion) -> Self::Output {
+        Quaternion {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+            w: self.w - rhs.w,
+        }
+    }
+}
+
+
+// MULTIPLICATION
+
+// Hamilton product, `a * b` rotates by `b` first and then by `a`
+impl Mul<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Self::Output {
+        Quaternion {
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        }
+    }
+}
+
+impl Mul<f64> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Quaternion {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+            w: self.w * rhs,
+        }
+    }
+}
+
+impl MulAssign<Quaternion> for Quaternion {
+    fn mul_assign(&mut self, rhs: Quaternion) {
+        *self = *self * rhs;
+    }
+}
+
+
+// NEGATION
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Self::Output {
+        Quaternion { x: -self.x, y: -self.y, z: -self.z, w: -self.w }
+    }
+}
+
+
+// CONVERSION
+
+impl From<Quaternion> for Matrix4 {
+    fn from(q: Quaternion) -> Self {
+        let Quaternion { x, y, z, w } = q;
+
+        [
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y + z * w),       2.0 * (x * z - y * w),       0.0],
+            [2.0 * (x * y - z * w),       1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z + x * w),       0.0],
+            [2.0 * (x * z + y * w),       2.0 * (y * z - x * w),       1.0 - 2.0 * (x * x + y * y), 0.0],
+            [0.0,                         0.0,                         0.0,                         1.0],
+        ].into()
+    }
+}
+
+impl Matrix4 {
+    /// The exponential map, rotation of `|v|` radians counter-clockwise around `v`
+    pub fn from_rotation_vector(v: Vector3) -> Matrix4 {
+        Quaternion::from_rotation_vector(v).into()
+    }
+
+    /// The logarithmic map of a matrix whose upper 3x3 part is a pure rotation
+    pub fn to_rotation_vector(&self) -> Vector3 {
+        Quaternion::from_matrix(*self).to_rotation_vector()
+    }
+}
+
+
+impl From<Vector4> for Quaternion {
+    fn from(v: Vector4) -> Self {
+        Quaternion { x: v.x, y: v.y, z: v.z, w: v.w }
+    }
+}
+
+impl From<Quaternion> for Vector4 {
+    fn from(q: Quaternion) -> Self {
+        Vector4 { x: q.x, y: q.y, z: q.z, w: q.w }
+    }
+}
+
+impl From<[f64; 4]> for Quaternion {
+    fn from(array: [f64; 4]) -> Self {
+        Quaternion {
+            x: array[0],
+            y: array[1],
+            z: array[2],
+            w: array[3],
+        }
+    }
+}
+
+impl From<Quaternion> for [f64; 4] {
+    fn from(q: Quaternion) -> Self {
+        [q.x, q.y, q.z, q.w]
+    }
+}
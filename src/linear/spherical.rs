@@ -0,0 +1,181 @@
+use std::f64::consts::PI;
+use super::{Vector2, Vector3};
+
+/// Which axis points up when converting to and from `Vector3`
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+pub enum UpAxis {
+    /// Y is up and the azimuth is measured from +Z towards +X
+    Y,
+
+    /// Z is up and the azimuth is measured from +X towards +Y
+    Z,
+}
+
+
+/// Spherical coordinates
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Spherical {
+    /// Distance from the origin
+    pub radius: f64,
+
+    /// Angle from the up axis in radians, normally in the range `[0, pi]`
+    pub polar: f64,
+
+    /// Angle around the up axis in radians
+    pub azimuth: f64,
+}
+
+
+/// Cylindrical coordinates
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Cylindrical {
+    /// Distance from the up axis
+    pub radius: f64,
+
+    /// Angle around the up axis in radians
+    pub azimuth: f64,
+
+    /// Distance along the up axis
+    pub height: f64,
+}
+
+
+impl UpAxis {
+    /// Split a vector into its up component and its two horizontal components, the
+    /// first of which is at zero azimuth
+    fn split(self, v: Vector3) -> (f64, Vector2) {
+        match self {
+            UpAxis::Y => (v.y, Vector2::new(v.z, v.x)),
+            UpAxis::Z => (v.z, Vector2::new(v.x, v.y)),
+        }
+    }
+
+    /// Inverse of `split`
+    fn join(self, up: f64, horizontal: Vector2) -> Vector3 {
+        match self {
+            UpAxis::Y => Vector3::new(horizontal.y, up, horizontal.x),
+            UpAxis::Z => Vector3::new(horizontal.x, horizontal.y, up),
+        }
+    }
+}
+
+
+/// Wrap an angle in radians into the range `(-pi, pi]`
+fn wrap_angle(angle: f64) -> f64 {
+    let wrapped = (angle + PI).rem_euclid(2.0 * PI) - PI;
+    if wrapped == -PI { PI } else { wrapped }
+}
+
+
+impl Spherical {
+    /// Create new spherical coordinates
+    pub fn new(radius: f64, polar: f64, azimuth: f64) -> Spherical {
+        Spherical { radius, polar, azimuth }
+    }
+
+
+    /// Convert from a cartesian vector. The zero vector has zero polar angle and azimuth.
+    pub fn from_vector(vector: Vector3, up: UpAxis) -> Spherical {
+        let (height, horizontal) = up.split(vector);
+        Spherical {
+            radius: vector.len(),
+            polar: horizontal.len().atan2(height),
+            azimuth: horizontal.angle(),
+        }
+    }
+
+    /// Convert to a cartesian vector
+    pub fn to_vector(&self, up: UpAxis) -> Vector3 {
+        let (sin, cos) = self.polar.sin_cos();
+        up.join(
+            self.radius * cos,
+            Vector2::from_polar(self.radius * sin, self.azimuth),
+        )
+    }
+
+
+    /// Equivalent coordinates with a non-negative radius, the polar angle in `[0, pi]` and the
+    /// azimuth in `(-pi, pi]`
+    pub fn wrap(&self) -> Spherical {
+        let (radius, mut polar, mut azimuth) = if self.radius < 0.0 {
+            (-self.radius, PI - self.polar, self.azimuth + PI)
+        } else {
+            (self.radius, self.polar, self.azimuth)
+        };
+
+        polar = polar.rem_euclid(2.0 * PI);
+        if polar > PI {
+            polar = 2.0 * PI - polar;
+            azimuth += PI;
+        }
+
+        Spherical { radius, polar, azimuth: wrap_angle(azimuth) }
+    }
+
+    /// Clamp the polar angle, eg. to keep an orbit camera away from the poles
+    pub fn clamp_polar(&self, min: f64, max: f64) -> Spherical {
+        Spherical { polar: self.polar.max(min).min(max), ..*self }
+    }
+
+
+    /// Interpolate the radius and polar angle linearly and the azimuth along the shortest arc
+    pub fn lerp(&self, other: Spherical, t: f64) -> Spherical {
+        let azimuth_delta = wrap_angle(other.azimuth - self.azimuth);
+        Spherical {
+            radius: self.radius + (other.radius - self.radius) * t,
+            polar: self.polar + (other.polar - self.polar) * t,
+            azimuth: self.azimuth + azimuth_delta * t,
+        }
+    }
+}
+
+
+impl Cylindrical {
+    /// Create new cylindrical coordinates
+    pub fn new(radius: f64, azimuth: f64, height: f64) -> Cylindrical {
+        Cylindrical { radius, azimuth, height }
+    }
+
+
+    /// Convert from a cartesian vector. Points on the up axis have zero azimuth.
+    pub fn from_vector(vector: Vector3, up: UpAxis) -> Cylindrical {
+        let (height, horizontal) = up.split(vector);
+        Cylindrical {
+            radius: horizontal.len(),
+            azimuth: horizontal.angle(),
+            height,
+        }
+    }
+
+    /// Convert to a cartesian vector
+    pub fn to_vector(&self, up: UpAxis) -> Vector3 {
+        up.join(self.height, Vector2::from_polar(self.radius, self.azimuth))
+    }
+}
+
+
+// CONVERSION
+
+impl From<Spherical> for Cylindrical {
+    fn from(spherical: Spherical) -> Self {
+        let (sin, cos) = spherical.polar.sin_cos();
+        Cylindrical {
+            radius: spherical.radius * sin,
+            azimuth: spherical.azimuth,
+            height: spherical.radius * cos,
+        }
+    }
+}
+
+impl From<Cylindrical> for Spherical {
+    fn from(cylindrical: Cylindrical) -> Self {
+        Spherical {
+            radius: cylindrical.radius.hypot(cylindrical.height),
+            polar: cylindrical.radius.atan2(cylindrical.height),
+            azimuth: cylindrical.azimuth,
+        }
+    }
+}
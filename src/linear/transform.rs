@@ -0,0 +1,217 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::{Mul, MulAssign};
+use super::{Vector3, Matrix4, Quaternion};
+
+/// Tolerance used when checking that a matrix is free of shear and projection
+const DECOMPOSE_TOLERANCE: f64 = 1e-6;
+
+
+/// A translation, rotation and scale, applied in the order scale, rotate, translate.
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Transform {
+    pub translation: Vector3,
+    pub rotation: Quaternion,
+    pub scale: Vector3,
+}
+
+
+/// Why a matrix could not be decomposed into a `Transform`
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+pub enum DecomposeError {
+    /// The bottom row is not `[0, 0, 0, 1]`, eg. a perspective projection
+    Projective,
+
+    /// An axis is scaled to zero
+    Singular,
+
+    /// The axes are not orthogonal after removing scale
+    Shear,
+}
+
+
+impl Transform {
+    /// Create a new transform
+    pub fn new(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Transform {
+        Transform { translation, rotation, scale }
+    }
+
+    /// The identity transform
+    pub fn identity() -> Transform {
+        Transform {
+            translation: Vector3::new(0.0, 0.0, 0.0),
+            rotation: Quaternion::identity(),
+            scale: Vector3::new(1.0, 1.0, 1.0),
+        }
+    }
+
+
+    /// A pure translation
+    pub fn from_translation(translation: Vector3) -> Transform {
+        Transform { translation, ..Transform::identity() }
+    }
+
+    /// A pure rotation
+    pub fn from_rotation(rotation: Quaternion) -> Transform {
+        Transform { rotation, ..Transform::identity() }
+    }
+
+    /// A pure scale
+    pub fn from_scale(scale: Vector3) -> Transform {
+        Transform { scale, ..Transform::identity() }
+    }
+
+
+    /// Transform a point
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        self.translation + self.rotation.rotate(self.scale * point)
+    }
+
+    /// Transform a direction, ignoring translation
+    pub fn transform_vector(&self, vector: Vector3) -> Vector3 {
+        self.rotation.rotate(self.scale * vector)
+    }
+
+
+    /// The inverse transform.
+    ///
+    /// Exact when the scale is uniform. Otherwise the true inverse contains shear, which
+    /// cannot be represented, and the result only inverts rotation and scale per axis.
+    pub fn inverse(&self) -> Transform {
+        let rotation = self.rotation.conjugate();
+        let scale = 1.0 / self.scale;
+
+        Transform {
+            translation: scale * rotation.rotate(-self.translation),
+            rotation,
+            scale,
+        }
+    }
+
+
+    /// Interpolate translation and scale linearly and rotation spherically
+    pub fn lerp(&self, other: Transform, t: f64) -> Transform {
+        Transform {
+            translation: self.translation + (other.translation - self.translation) * t,
+            rotation: self.rotation.slerp(other.rotation, t),
+            scale: self.scale + (other.scale - self.scale) * t,
+        }
+    }
+
+
+    /// The equivalent matrix
+    pub fn to_matrix(&self) -> Matrix4 {
+        Matrix4::translated(self.translation) * Matrix4::from(self.rotation) * Matrix4::scaled(self.scale)
+    }
+}
+
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::identity()
+    }
+}
+
+
+impl Matrix4 {
+    /// Decompose an affine matrix into translation, rotation and scale.
+    ///
+    /// Mirroring (a negative determinant) is represented as negative scale on all three axes.
+    pub fn decompose(&self) -> Result<Transform, DecomposeError> {
+        let m: [[f64; 4]; 4] = (*self).into();
+
+        if m[0][3].abs() > DECOMPOSE_TOLERANCE || m[1][3].abs() > DECOMPOSE_TOLERANCE ||
+            m[2][3].abs() > DECOMPOSE_TOLERANCE || (m[3][3] - 1.0).abs() > DECOMPOSE_TOLERANCE {
+            return Err(DecomposeError::Projective);
+        }
+
+        let mut axes = [
+            Vector3::new(m[0][0], m[0][1], m[0][2]),
+            Vector3::new(m[1][0], m[1][1], m[1][2]),
+            Vector3::new(m[2][0], m[2][1], m[2][2]),
+        ];
+
+        let mut scale = Vector3::new(axes[0].len(), axes[1].len(), axes[2].len());
+        if scale.x < DECOMPOSE_TOLERANCE || scale.y < DECOMPOSE_TOLERANCE || scale.z < DECOMPOSE_TOLERANCE {
+            return Err(DecomposeError::Singular);
+        }
+
+        axes[0] = axes[0] / scale.x;
+        axes[1] = axes[1] / scale.y;
+        axes[2] = axes[2] / scale.z;
+
+        if axes[0].dot(axes[1]).abs() > DECOMPOSE_TOLERANCE ||
+            axes[0].dot(axes[2]).abs() > DECOMPOSE_TOLERANCE ||
+            axes[1].dot(axes[2]).abs() > DECOMPOSE_TOLERANCE {
+            return Err(DecomposeError::Shear);
+        }
+
+        if axes[0].cross(axes[1]).dot(axes[2]) < 0.0 {
+            scale = -scale;
+            for axis in axes.iter_mut() {
+                *axis = -*axis;
+            }
+        }
+
+        let rotation = Matrix4::from([
+            [axes[0].x, axes[0].y, axes[0].z, 0.0],
+            [axes[1].x, axes[1].y, axes[1].z, 0.0],
+            [axes[2].x, axes[2].y, axes[2].z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        Ok(Transform {
+            translation: Vector3::new(m[3][0], m[3][1], m[3][2]),
+            rotation: Quaternion::from_matrix(rotation),
+            scale,
+        })
+    }
+}
+
+
+// MULTIPLICATION
+
+// Composition, `a * b` applies `b` first and then `a`.
+//
+// Like the inverse, this is exact unless `a` has non-uniform scale and `b` is rotated.
+impl Mul<Transform> for Transform {
+    type Output = Transform;
+
+    fn mul(self, rhs: Transform) -> Self::Output {
+        Transform {
+            translation: self.transform_point(rhs.translation),
+            rotation: self.rotation * rhs.rotation,
+            scale: self.scale * rhs.scale,
+        }
+    }
+}
+
+impl MulAssign<Transform> for Transform {
+    fn mul_assign(&mut self, rhs: Transform) {
+        *self = *self * rhs;
+    }
+}
+
+
+// CONVERSION
+
+impl From<Transform> for Matrix4 {
+    fn from(transform: Transform) -> Self {
+        transform.to_matrix()
+    }
+}
+
+
+impl fmt::Display for DecomposeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecomposeError::Projective => write!(f, "matrix has a projective component"),
+            DecomposeError::Singular => write!(f, "matrix scales an axis to zero"),
+            DecomposeError::Shear => write!(f, "matrix contains shear"),
+        }
+    }
+}
+
+impl Error for DecomposeError {}
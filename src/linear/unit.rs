@@ -0,0 +1,151 @@
+use std::ops::{Deref, Neg};
+use super::{Vector2, Vector3, Vector4};
+
+/// Vectors that can be normalized to unit length
+pub trait Normalize: Sized {
+    /// Normalized unit vector, or `None` if the length is zero or not finite
+    fn try_normal(&self) -> Option<Self>;
+}
+
+
+/// A vector guaranteed to have unit length.
+///
+/// Constructed by normalizing a vector, which fails for zero and non-finite vectors.
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Unit<T> {
+    value: T,
+}
+
+
+impl<T: Normalize> Unit<T> {
+    /// Normalize a vector, or `None` if its length is zero or not finite
+    pub fn new(vector: T) -> Option<Unit<T>> {
+        vector.try_normal().map(Unit::new_unchecked)
+    }
+}
+
+
+impl<T> Unit<T> {
+    /// Wrap a vector that is already known to have unit length, without checking
+    pub fn new_unchecked(value: T) -> Unit<T> {
+        Unit { value }
+    }
+
+    /// Unwrap the underlying vector
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+
+impl Unit<Vector2> {
+    /// The unit vector along the x axis
+    pub fn x_axis() -> Unit<Vector2> {
+        Unit::new_unchecked(Vector2::new(1.0, 0.0))
+    }
+
+    /// The unit vector along the y axis
+    pub fn y_axis() -> Unit<Vector2> {
+        Unit::new_unchecked(Vector2::new(0.0, 1.0))
+    }
+}
+
+
+impl Unit<Vector3> {
+    /// The unit vector along the x axis
+    pub fn x_axis() -> Unit<Vector3> {
+        Unit::new_unchecked(Vector3::new(1.0, 0.0, 0.0))
+    }
+
+    /// The unit vector along the y axis
+    pub fn y_axis() -> Unit<Vector3> {
+        Unit::new_unchecked(Vector3::new(0.0, 1.0, 0.0))
+    }
+
+    /// The unit vector along the z axis
+    pub fn z_axis() -> Unit<Vector3> {
+        Unit::new_unchecked(Vector3::new(0.0, 0.0, 1.0))
+    }
+
+    /// Tangent and bitangent completing a right-handed orthonormal basis around this vector,
+    /// such that `tangent.cross(bitangent)` is the vector itself
+    pub fn orthonormal_basis(&self) -> (Vector3, Vector3) {
+        // Duff et al., "Building an Orthonormal Basis, Revisited" (2017)
+        let n = self.value;
+        let sign = 1.0f64.copysign(n.z);
+        let a = -1.0 / (sign + n.z);
+        let b = n.x * n.y * a;
+
+        (
+            Vector3::new(1.0 + sign * n.x * n.x * a, sign * b, -sign * n.x),
+            Vector3::new(b, sign + n.y * n.y * a, -n.y),
+        )
+    }
+}
+
+
+impl Normalize for Vector2 {
+    fn try_normal(&self) -> Option<Vector2> {
+        Vector2::try_normal(self)
+    }
+}
+
+impl Normalize for Vector3 {
+    fn try_normal(&self) -> Option<Vector3> {
+        Vector3::try_normal(self)
+    }
+}
+
+impl Normalize for Vector4 {
+    fn try_normal(&self) -> Option<Vector4> {
+        Vector4::try_normal(self)
+    }
+}
+
+
+impl<T> Deref for Unit<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> AsRef<T> for Unit<T> {
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+
+// NEGATION
+
+impl<T: Neg<Output = T>> Neg for Unit<T> {
+    type Output = Unit<T>;
+
+    fn neg(self) -> Self::Output {
+        Unit { value: -self.value }
+    }
+}
+
+
+// CONVERSION
+
+impl From<Unit<Vector2>> for Vector2 {
+    fn from(unit: Unit<Vector2>) -> Self {
+        unit.value
+    }
+}
+
+impl From<Unit<Vector3>> for Vector3 {
+    fn from(unit: Unit<Vector3>) -> Self {
+        unit.value
+    }
+}
+
+impl From<Unit<Vector4>> for Vector4 {
+    fn from(unit: Unit<Vector4>) -> Self {
+        unit.value
+    }
+}
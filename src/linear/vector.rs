@@ -3,17 +3,22 @@ pub use self::vector3::Vector3;
 pub use self::vector4::Vector4;
 
 mod vector2 {
-    use std::cmp::PartialEq;
+    use std::cmp::{PartialEq, Ordering};
     use std::ops::{
         Add, AddAssign,
         Sub, SubAssign,
         Mul, MulAssign,
         Div, DivAssign,
         Neg,
+        Index, IndexMut,
     };
+    use std::{array, slice};
+    use std::iter::{Sum, Product};
+    use super::super::{Unit, Axis2, Vector2i};
 
     #[derive(Copy, Clone)]
     #[derive(Debug)]
+    #[repr(C)]
     pub struct Vector2 {
         pub x: f64,
         pub y: f64,
@@ -64,6 +69,212 @@ mod vector2 {
         pub fn normal(&self) -> Vector2 {
             *self / self.len()
         }
+
+        /// Normalized unit vector, or `None` if the length is zero or not finite
+        pub fn try_normal(&self) -> Option<Vector2> {
+            let len = self.len();
+            if len > 0.0 && len.is_finite() {
+                Some(*self / len)
+            } else {
+                None
+            }
+        }
+
+        /// Normalized unit vector, or `default` if the length is zero or not finite
+        pub fn normal_or(&self, default: Vector2) -> Vector2 {
+            self.try_normal().unwrap_or(default)
+        }
+
+        /// Normalized unit vector, or the zero vector if the length is zero or not finite
+        pub fn normal_or_zero(&self) -> Vector2 {
+            self.normal_or(Vector2::new(0.0, 0.0))
+        }
+
+
+        /// Reflect off a surface with the given normal
+        pub fn reflect(&self, normal: Unit<Vector2>) -> Vector2 {
+            *self - 2.0 * self.dot(*normal) * *normal
+        }
+
+        /// Refract through a surface with the given normal, where `eta` is the ratio of the
+        /// refractive indices. Both vectors should face each other and this vector should have
+        /// unit length. Returns `None` on total internal reflection.
+        pub fn refract(&self, normal: Unit<Vector2>, eta: f64) -> Option<Vector2> {
+            let cos = self.dot(*normal);
+            let k = 1.0 - eta * eta * (1.0 - cos * cos);
+            if k < 0.0 {
+                None
+            } else {
+                Some(eta * *self - (eta * cos + k.sqrt()) * *normal)
+            }
+        }
+
+
+        /// The component parallel to another vector, zero if the other vector is zero
+        pub fn project_onto(&self, other: Vector2) -> Vector2 {
+            let square_length = other.square_length();
+            if square_length == 0.0 {
+                Vector2::new(0.0, 0.0)
+            } else {
+                other * (self.dot(other) / square_length)
+            }
+        }
+
+        /// The component perpendicular to another vector
+        pub fn reject_from(&self, other: Vector2) -> Vector2 {
+            *self - self.project_onto(other)
+        }
+
+
+        /// Unsigned angle between vectors in radians, in the range `[0, pi]`
+        pub fn angle_between(&self, other: Vector2) -> f64 {
+            self.cross(other).abs().atan2(self.dot(other))
+        }
+
+        /// Signed angle in radians, in the range `[-pi, pi]`, rotating this vector onto another.
+        /// Positive angles are counter-clockwise.
+        pub fn angle_to(&self, other: Vector2) -> f64 {
+            self.cross(other).atan2(self.dot(other))
+        }
+
+
+        /// The perpendicular vector, rotated 90 degrees counter-clockwise
+        pub fn perp(&self) -> Vector2 {
+            Vector2 { x: -self.y, y: self.x }
+        }
+
+        /// The perpendicular vector, rotated 90 degrees clockwise
+        pub fn perp_cw(&self) -> Vector2 {
+            Vector2 { x: self.y, y: -self.x }
+        }
+
+        /// 2D cross product (perp-dot product), the z component of the 3D cross product
+        pub fn cross(&self, other: Vector2) -> f64 {
+            self.x * other.y - self.y * other.x
+        }
+
+
+        /// Unit vector at an angle in radians, counter-clockwise from the x axis
+        pub fn from_angle(angle: f64) -> Vector2 {
+            let (sin, cos) = angle.sin_cos();
+            Vector2 { x: cos, y: sin }
+        }
+
+        /// Angle in radians counter-clockwise from the x axis, in the range `[-pi, pi]`
+        pub fn angle(&self) -> f64 {
+            self.y.atan2(self.x)
+        }
+
+        /// Create a vector from a length and an angle in radians
+        pub fn from_polar(radius: f64, angle: f64) -> Vector2 {
+            Vector2::from_angle(angle) * radius
+        }
+
+        /// The length and angle in radians of this vector
+        pub fn to_polar(&self) -> (f64, f64) {
+            (self.len(), self.angle())
+        }
+
+
+        /// Rotate counter-clockwise around the origin by an angle in radians
+        pub fn rotate(&self, angle: f64) -> Vector2 {
+            let (sin, cos) = angle.sin_cos();
+            Vector2 {
+                x: self.x * cos - self.y * sin,
+                y: self.x * sin + self.y * cos,
+            }
+        }
+
+        /// Rotate counter-clockwise around a pivot by an angle in radians
+        pub fn rotate_around(&self, pivot: Vector2, angle: f64) -> Vector2 {
+            pivot + (*self - pivot).rotate(angle)
+        }
+
+
+        /// The average of points, or `None` if there are none
+        pub fn centroid<I: IntoIterator<Item = Vector2>>(points: I) -> Option<Vector2> {
+            let (sum, count) = points.into_iter()
+                .fold((Vector2::new(0.0, 0.0), 0), |(sum, count), point| (sum + point, count + 1));
+
+            if count == 0 {
+                None
+            } else {
+                Some(sum / count as f64)
+            }
+        }
+
+        /// The average of points weighted by their weights, or `None` if the weights sum to zero
+        pub fn weighted_average<I: IntoIterator<Item = (Vector2, f64)>>(points: I) -> Option<Vector2> {
+            let (sum, total) = points.into_iter()
+                .fold((Vector2::new(0.0, 0.0), 0.0), |(sum, total), (point, weight)| (sum + point * weight, total + weight));
+
+            if total == 0.0 {
+                None
+            } else {
+                Some(sum / total)
+            }
+        }
+
+
+        /// Component-wise minimum
+        pub fn component_min(&self, other: Vector2) -> Vector2 {
+            Vector2::new(self.x.min(other.x), self.y.min(other.y))
+        }
+
+        /// Component-wise maximum
+        pub fn component_max(&self, other: Vector2) -> Vector2 {
+            Vector2::new(self.x.max(other.x), self.y.max(other.y))
+        }
+
+
+        /// Compare lexicographically by the IEEE 754 total order of the components, which
+        /// orders `-0.0` before `0.0` and places NaNs at the ends
+        pub fn total_cmp(&self, other: &Vector2) -> Ordering {
+            self.x.total_cmp(&other.x)
+                .then_with(|| self.y.total_cmp(&other.y))
+        }
+
+        /// Snap to a grid with cells of size `tolerance`, or `None` for NaN, overflow or a non-positive tolerance
+        pub fn quantize(&self, tolerance: f64) -> Option<Vector2i> {
+            if tolerance.is_nan() || tolerance <= 0.0 {
+                return None;
+            }
+            let scaled = *self / tolerance;
+            Vector2i::try_from_float(Vector2::new(scaled.x.round(), scaled.y.round())).ok()
+        }
+
+
+        /// View the components as an array
+        pub fn as_array(&self) -> &[f64; 2] {
+            // The struct is `repr(C)` with 2 fields of the same type, so it has the layout of an array
+            unsafe { &*(self as *const Vector2 as *const [f64; 2]) }
+        }
+
+        /// View the components as a mutable array
+        pub fn as_mut_array(&mut self) -> &mut [f64; 2] {
+            unsafe { &mut *(self as *mut Vector2 as *mut [f64; 2]) }
+        }
+
+        /// Iterate over the components
+        pub fn iter(&self) -> slice::Iter<'_, f64> {
+            self.as_array().iter()
+        }
+
+        /// Iterate mutably over the components
+        pub fn iter_mut(&mut self) -> slice::IterMut<'_, f64> {
+            self.as_mut_array().iter_mut()
+        }
+
+        /// The axis of the component with the largest magnitude, the first on ties
+        pub fn dominant_axis(&self) -> Axis2 {
+            let mut dominant = 0;
+            for (i, component) in self.iter().enumerate() {
+                if component.abs() > self[dominant].abs() {
+                    dominant = i;
+                }
+            }
+            Axis2::ALL[dominant]
+        }
     }
 
 
@@ -73,10 +284,6 @@ mod vector2 {
         fn eq(&self, other: &Vector2) -> bool {
             self.x == other.x && self.y == other.y
         }
-
-        fn ne(&self, other: &Vector2) -> bool {
-            self.x != other.x || self.y != other.y
-        }
     }
 
 
@@ -226,6 +433,91 @@ mod vector2 {
     }
 
 
+    // INDEXING
+
+    impl Index<usize> for Vector2 {
+        type Output = f64;
+
+        fn index(&self, index: usize) -> &f64 {
+            &self.as_array()[index]
+        }
+    }
+
+    impl IndexMut<usize> for Vector2 {
+        fn index_mut(&mut self, index: usize) -> &mut f64 {
+            &mut self.as_mut_array()[index]
+        }
+    }
+
+    impl Index<Axis2> for Vector2 {
+        type Output = f64;
+
+        fn index(&self, axis: Axis2) -> &f64 {
+            &self[axis.index()]
+        }
+    }
+
+    impl IndexMut<Axis2> for Vector2 {
+        fn index_mut(&mut self, axis: Axis2) -> &mut f64 {
+            &mut self[axis.index()]
+        }
+    }
+
+
+    // ITERATION
+
+    impl Sum<Vector2> for Vector2 {
+        fn sum<I: Iterator<Item = Vector2>>(iter: I) -> Vector2 {
+            iter.fold(Vector2::new(0.0, 0.0), Add::add)
+        }
+    }
+
+    impl<'a> Sum<&'a Vector2> for Vector2 {
+        fn sum<I: Iterator<Item = &'a Vector2>>(iter: I) -> Vector2 {
+            iter.cloned().sum()
+        }
+    }
+
+    impl Product<Vector2> for Vector2 {
+        fn product<I: Iterator<Item = Vector2>>(iter: I) -> Vector2 {
+            iter.fold(Vector2::new(1.0, 1.0), Mul::mul)
+        }
+    }
+
+    impl<'a> Product<&'a Vector2> for Vector2 {
+        fn product<I: Iterator<Item = &'a Vector2>>(iter: I) -> Vector2 {
+            iter.cloned().product()
+        }
+    }
+
+    impl IntoIterator for Vector2 {
+        type Item = f64;
+        type IntoIter = array::IntoIter<f64, 2>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            IntoIterator::into_iter(*self.as_array())
+        }
+    }
+
+    impl<'a> IntoIterator for &'a Vector2 {
+        type Item = &'a f64;
+        type IntoIter = slice::Iter<'a, f64>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<'a> IntoIterator for &'a mut Vector2 {
+        type Item = &'a mut f64;
+        type IntoIter = slice::IterMut<'a, f64>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
+
     // CONVERSION
     impl From<[f64; 2]> for Vector2 {
         fn from(array: [f64; 2]) -> Self {
@@ -256,20 +548,49 @@ mod vector2 {
             [vector.x as f32, vector.y as f32]
         }
     }
+
+    impl From<(f64, f64)> for Vector2 {
+        fn from((x, y): (f64, f64)) -> Self {
+            Vector2 { x, y }
+        }
+    }
+
+    impl From<Vector2> for (f64, f64) {
+        fn from(vector: Vector2) -> Self {
+            (vector.x, vector.y)
+        }
+    }
+
+    impl AsRef<[f64; 2]> for Vector2 {
+        fn as_ref(&self) -> &[f64; 2] {
+            self.as_array()
+        }
+    }
+
+    impl AsMut<[f64; 2]> for Vector2 {
+        fn as_mut(&mut self) -> &mut [f64; 2] {
+            self.as_mut_array()
+        }
+    }
 }
 
 mod vector3 {
-    use std::cmp::PartialEq;
+    use std::cmp::{PartialEq, Ordering};
     use std::ops::{
         Add, AddAssign,
         Sub, SubAssign,
         Mul, MulAssign,
         Div, DivAssign,
         Neg,
+        Index, IndexMut,
     };
+    use std::{array, slice};
+    use std::iter::{Sum, Product};
+    use super::super::{Unit, Axis3, Vector3i};
 
     #[derive(Copy, Clone)]
     #[derive(Debug)]
+    #[repr(C)]
     pub struct Vector3 {
         pub x: f64,
         pub y: f64,
@@ -304,6 +625,26 @@ mod vector3 {
             *self / self.len()
         }
 
+        /// Normalized unit vector, or `None` if the length is zero or not finite
+        pub fn try_normal(&self) -> Option<Vector3> {
+            let len = self.len();
+            if len > 0.0 && len.is_finite() {
+                Some(*self / len)
+            } else {
+                None
+            }
+        }
+
+        /// Normalized unit vector, or `default` if the length is zero or not finite
+        pub fn normal_or(&self, default: Vector3) -> Vector3 {
+            self.try_normal().unwrap_or(default)
+        }
+
+        /// Normalized unit vector, or the zero vector if the length is zero or not finite
+        pub fn normal_or_zero(&self) -> Vector3 {
+            self.normal_or(Vector3::new(0.0, 0.0, 0.0))
+        }
+
 
         /// Cross product
         pub fn cross(&self, other: Vector3) -> Vector3 {
@@ -313,6 +654,167 @@ mod vector3 {
                 z: self.x*other.y - self.y*other.x
             }
         }
+
+
+        /// Reflect off a surface with the given normal
+        pub fn reflect(&self, normal: Unit<Vector3>) -> Vector3 {
+            *self - 2.0 * self.dot(*normal) * *normal
+        }
+
+        /// Refract through a surface with the given normal, where `eta` is the ratio of the
+        /// refractive indices. Both vectors should face each other and this vector should have
+        /// unit length. Returns `None` on total internal reflection.
+        pub fn refract(&self, normal: Unit<Vector3>, eta: f64) -> Option<Vector3> {
+            let cos = self.dot(*normal);
+            let k = 1.0 - eta * eta * (1.0 - cos * cos);
+            if k < 0.0 {
+                None
+            } else {
+                Some(eta * *self - (eta * cos + k.sqrt()) * *normal)
+            }
+        }
+
+
+        /// The component parallel to another vector, zero if the other vector is zero
+        pub fn project_onto(&self, other: Vector3) -> Vector3 {
+            let square_length = other.dot(other);
+            if square_length == 0.0 {
+                Vector3::new(0.0, 0.0, 0.0)
+            } else {
+                other * (self.dot(other) / square_length)
+            }
+        }
+
+        /// The component perpendicular to another vector
+        pub fn reject_from(&self, other: Vector3) -> Vector3 {
+            *self - self.project_onto(other)
+        }
+
+        /// Projection onto the plane through the origin with the given normal
+        pub fn project_onto_plane(&self, normal: Unit<Vector3>) -> Vector3 {
+            *self - self.dot(*normal) * *normal
+        }
+
+
+        /// Unsigned angle between vectors in radians, in the range `[0, pi]`
+        pub fn angle_between(&self, other: Vector3) -> f64 {
+            self.cross(other).len().atan2(self.dot(other))
+        }
+
+        /// Signed angle in radians, in the range `[-pi, pi]`, rotating this vector onto another
+        /// around an axis. Positive angles are counter-clockwise when looking down the axis.
+        /// Both vectors are first projected onto the plane perpendicular to the axis.
+        pub fn signed_angle(&self, other: Vector3, axis: Unit<Vector3>) -> f64 {
+            let from = self.project_onto_plane(axis);
+            let to = other.project_onto_plane(axis);
+            from.cross(to).dot(*axis).atan2(from.dot(to))
+        }
+
+
+        /// An arbitrary unit vector orthogonal to this one, zero if this vector is zero
+        pub fn any_orthogonal(&self) -> Vector3 {
+            let orthogonal = if self.x.abs() > self.z.abs() {
+                Vector3::new(-self.y, self.x, 0.0)
+            } else {
+                Vector3::new(0.0, -self.z, self.y)
+            };
+
+            orthogonal.normal_or_zero()
+        }
+
+        /// Tangent and bitangent completing a right-handed orthonormal basis around this
+        /// vector, such that `tangent.cross(bitangent)` is the normalized vector. `None` if the
+        /// vector is zero or not finite.
+        pub fn orthonormal_basis(&self) -> Option<(Vector3, Vector3)> {
+            Unit::new(*self).map(|normal| normal.orthonormal_basis())
+        }
+
+
+        /// The average of points, or `None` if there are none
+        pub fn centroid<I: IntoIterator<Item = Vector3>>(points: I) -> Option<Vector3> {
+            let (sum, count) = points.into_iter()
+                .fold((Vector3::new(0.0, 0.0, 0.0), 0), |(sum, count), point| (sum + point, count + 1));
+
+            if count == 0 {
+                None
+            } else {
+                Some(sum / count as f64)
+            }
+        }
+
+        /// The average of points weighted by their weights, or `None` if the weights sum to zero
+        pub fn weighted_average<I: IntoIterator<Item = (Vector3, f64)>>(points: I) -> Option<Vector3> {
+            let (sum, total) = points.into_iter()
+                .fold((Vector3::new(0.0, 0.0, 0.0), 0.0), |(sum, total), (point, weight)| (sum + point * weight, total + weight));
+
+            if total == 0.0 {
+                None
+            } else {
+                Some(sum / total)
+            }
+        }
+
+
+        /// Component-wise minimum
+        pub fn component_min(&self, other: Vector3) -> Vector3 {
+            Vector3::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+        }
+
+        /// Component-wise maximum
+        pub fn component_max(&self, other: Vector3) -> Vector3 {
+            Vector3::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+        }
+
+
+        /// Compare lexicographically by the IEEE 754 total order of the components, which
+        /// orders `-0.0` before `0.0` and places NaNs at the ends
+        pub fn total_cmp(&self, other: &Vector3) -> Ordering {
+            self.x.total_cmp(&other.x)
+                .then_with(|| self.y.total_cmp(&other.y))
+                .then_with(|| self.z.total_cmp(&other.z))
+        }
+
+        /// Snap to a grid with cells of size `tolerance`, or `None` for NaN, overflow or a non-positive tolerance
+        pub fn quantize(&self, tolerance: f64) -> Option<Vector3i> {
+            if tolerance.is_nan() || tolerance <= 0.0 {
+                return None;
+            }
+            let scaled = *self / tolerance;
+            Vector3i::try_from_float(Vector3::new(scaled.x.round(), scaled.y.round(), scaled.z.round())).ok()
+        }
+
+
+        /// View the components as an array
+        pub fn as_array(&self) -> &[f64; 3] {
+            // The struct is `repr(C)` with 3 fields of the same type, so it has the layout of an array
+            unsafe { &*(self as *const Vector3 as *const [f64; 3]) }
+        }
+
+        /// View the components as a mutable array
+        pub fn as_mut_array(&mut self) -> &mut [f64; 3] {
+            unsafe { &mut *(self as *mut Vector3 as *mut [f64; 3]) }
+        }
+
+        /// Iterate over the components
+        pub fn iter(&self) -> slice::Iter<'_, f64> {
+            self.as_array().iter()
+        }
+
+        /// Iterate mutably over the components
+        pub fn iter_mut(&mut self) -> slice::IterMut<'_, f64> {
+            self.as_mut_array().iter_mut()
+        }
+
+        /// The axis of the component with the largest magnitude, the first on ties
+        pub fn dominant_axis(&self) -> Axis3 {
+            let mut dominant = 0;
+            for (i, component) in self.iter().enumerate() {
+                if component.abs() > self[dominant].abs() {
+                    dominant = i;
+                }
+            }
+            Axis3::ALL[dominant]
+        }
     }
 
 
@@ -322,10 +824,6 @@ mod vector3 {
         fn eq(&self, other: &Vector3) -> bool {
             self.x == other.x && self.y == other.y && self.z == other.z
         }
-
-        fn ne(&self, other: &Vector3) -> bool {
-            self.x != other.x || self.y != other.y || self.z != other.z
-        }
     }
 
 
@@ -488,6 +986,91 @@ mod vector3 {
     }
 
 
+    // INDEXING
+
+    impl Index<usize> for Vector3 {
+        type Output = f64;
+
+        fn index(&self, index: usize) -> &f64 {
+            &self.as_array()[index]
+        }
+    }
+
+    impl IndexMut<usize> for Vector3 {
+        fn index_mut(&mut self, index: usize) -> &mut f64 {
+            &mut self.as_mut_array()[index]
+        }
+    }
+
+    impl Index<Axis3> for Vector3 {
+        type Output = f64;
+
+        fn index(&self, axis: Axis3) -> &f64 {
+            &self[axis.index()]
+        }
+    }
+
+    impl IndexMut<Axis3> for Vector3 {
+        fn index_mut(&mut self, axis: Axis3) -> &mut f64 {
+            &mut self[axis.index()]
+        }
+    }
+
+
+    // ITERATION
+
+    impl Sum<Vector3> for Vector3 {
+        fn sum<I: Iterator<Item = Vector3>>(iter: I) -> Vector3 {
+            iter.fold(Vector3::new(0.0, 0.0, 0.0), Add::add)
+        }
+    }
+
+    impl<'a> Sum<&'a Vector3> for Vector3 {
+        fn sum<I: Iterator<Item = &'a Vector3>>(iter: I) -> Vector3 {
+            iter.cloned().sum()
+        }
+    }
+
+    impl Product<Vector3> for Vector3 {
+        fn product<I: Iterator<Item = Vector3>>(iter: I) -> Vector3 {
+            iter.fold(Vector3::new(1.0, 1.0, 1.0), Mul::mul)
+        }
+    }
+
+    impl<'a> Product<&'a Vector3> for Vector3 {
+        fn product<I: Iterator<Item = &'a Vector3>>(iter: I) -> Vector3 {
+            iter.cloned().product()
+        }
+    }
+
+    impl IntoIterator for Vector3 {
+        type Item = f64;
+        type IntoIter = array::IntoIter<f64, 3>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            IntoIterator::into_iter(*self.as_array())
+        }
+    }
+
+    impl<'a> IntoIterator for &'a Vector3 {
+        type Item = &'a f64;
+        type IntoIter = slice::Iter<'a, f64>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<'a> IntoIterator for &'a mut Vector3 {
+        type Item = &'a mut f64;
+        type IntoIter = slice::IterMut<'a, f64>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
+
     // CONVERSION
     impl From<[f64; 3]> for Vector3 {
         fn from(array: [f64; 3]) -> Self {
@@ -522,20 +1105,49 @@ mod vector3 {
             [vector.x as f32, vector.y as f32, vector.z as f32]
         }
     }
+
+    impl From<(f64, f64, f64)> for Vector3 {
+        fn from((x, y, z): (f64, f64, f64)) -> Self {
+            Vector3 { x, y, z }
+        }
+    }
+
+    impl From<Vector3> for (f64, f64, f64) {
+        fn from(vector: Vector3) -> Self {
+            (vector.x, vector.y, vector.z)
+        }
+    }
+
+    impl AsRef<[f64; 3]> for Vector3 {
+        fn as_ref(&self) -> &[f64; 3] {
+            self.as_array()
+        }
+    }
+
+    impl AsMut<[f64; 3]> for Vector3 {
+        fn as_mut(&mut self) -> &mut [f64; 3] {
+            self.as_mut_array()
+        }
+    }
 }
 
 mod vector4 {
-    use std::cmp::PartialEq;
+    use std::cmp::{PartialEq, Ordering};
     use std::ops::{
         Add, AddAssign,
         Sub, SubAssign,
         Mul, MulAssign,
         Div, DivAssign,
         Neg,
+        Index, IndexMut,
     };
+    use std::{array, slice};
+    use std::iter::{Sum, Product};
+    use super::super::{Axis, Vector4i};
 
     #[derive(Copy, Clone)]
     #[derive(Debug)]
+    #[repr(C)]
     pub struct Vector4 {
         pub x: f64,
         pub y: f64,
@@ -569,6 +1181,114 @@ mod vector4 {
         pub fn normal(&self) -> Vector4 {
             *self / self.len()
         }
+
+        /// Normalized unit vector, or `None` if the length is zero or not finite
+        pub fn try_normal(&self) -> Option<Vector4> {
+            let len = self.len();
+            if len > 0.0 && len.is_finite() {
+                Some(*self / len)
+            } else {
+                None
+            }
+        }
+
+        /// Normalized unit vector, or `default` if the length is zero or not finite
+        pub fn normal_or(&self, default: Vector4) -> Vector4 {
+            self.try_normal().unwrap_or(default)
+        }
+
+        /// Normalized unit vector, or the zero vector if the length is zero or not finite
+        pub fn normal_or_zero(&self) -> Vector4 {
+            self.normal_or(Vector4::new(0.0, 0.0, 0.0, 0.0))
+        }
+
+
+        /// The average of points, or `None` if there are none
+        pub fn centroid<I: IntoIterator<Item = Vector4>>(points: I) -> Option<Vector4> {
+            let (sum, count) = points.into_iter()
+                .fold((Vector4::new(0.0, 0.0, 0.0, 0.0), 0), |(sum, count), point| (sum + point, count + 1));
+
+            if count == 0 {
+                None
+            } else {
+                Some(sum / count as f64)
+            }
+        }
+
+        /// The average of points weighted by their weights, or `None` if the weights sum to zero
+        pub fn weighted_average<I: IntoIterator<Item = (Vector4, f64)>>(points: I) -> Option<Vector4> {
+            let (sum, total) = points.into_iter()
+                .fold((Vector4::new(0.0, 0.0, 0.0, 0.0), 0.0), |(sum, total), (point, weight)| (sum + point * weight, total + weight));
+
+            if total == 0.0 {
+                None
+            } else {
+                Some(sum / total)
+            }
+        }
+
+
+        /// Component-wise minimum
+        pub fn component_min(&self, other: Vector4) -> Vector4 {
+            Vector4::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z), self.w.min(other.w))
+        }
+
+        /// Component-wise maximum
+        pub fn component_max(&self, other: Vector4) -> Vector4 {
+            Vector4::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z), self.w.max(other.w))
+        }
+
+
+        /// Compare lexicographically by the IEEE 754 total order of the components, which
+        /// orders `-0.0` before `0.0` and places NaNs at the ends
+        pub fn total_cmp(&self, other: &Vector4) -> Ordering {
+            self.x.total_cmp(&other.x)
+                .then_with(|| self.y.total_cmp(&other.y))
+                .then_with(|| self.z.total_cmp(&other.z))
+                .then_with(|| self.w.total_cmp(&other.w))
+        }
+
+        /// Snap to a grid with cells of size `tolerance`, or `None` for NaN, overflow or a non-positive tolerance
+        pub fn quantize(&self, tolerance: f64) -> Option<Vector4i> {
+            if tolerance.is_nan() || tolerance <= 0.0 {
+                return None;
+            }
+            let scaled = *self / tolerance;
+            Vector4i::try_from_float(Vector4::new(scaled.x.round(), scaled.y.round(), scaled.z.round(), scaled.w.round())).ok()
+        }
+
+
+        /// View the components as an array
+        pub fn as_array(&self) -> &[f64; 4] {
+            // The struct is `repr(C)` with 4 fields of the same type, so it has the layout of an array
+            unsafe { &*(self as *const Vector4 as *const [f64; 4]) }
+        }
+
+        /// View the components as a mutable array
+        pub fn as_mut_array(&mut self) -> &mut [f64; 4] {
+            unsafe { &mut *(self as *mut Vector4 as *mut [f64; 4]) }
+        }
+
+        /// Iterate over the components
+        pub fn iter(&self) -> slice::Iter<'_, f64> {
+            self.as_array().iter()
+        }
+
+        /// Iterate mutably over the components
+        pub fn iter_mut(&mut self) -> slice::IterMut<'_, f64> {
+            self.as_mut_array().iter_mut()
+        }
+
+        /// The axis of the component with the largest magnitude, the first on ties
+        pub fn dominant_axis(&self) -> Axis {
+            let mut dominant = 0;
+            for (i, component) in self.iter().enumerate() {
+                if component.abs() > self[dominant].abs() {
+                    dominant = i;
+                }
+            }
+            Axis::ALL[dominant]
+        }
     }
 
 
@@ -578,10 +1298,6 @@ mod vector4 {
         fn eq(&self, other: &Vector4) -> bool {
             self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
         }
-
-        fn ne(&self, other: &Vector4) -> bool {
-            self.x != other.x || self.y != other.y || self.z != other.z || self.w != other.w
-        }
     }
 
 
@@ -757,6 +1473,91 @@ mod vector4 {
     }
 
 
+    // INDEXING
+
+    impl Index<usize> for Vector4 {
+        type Output = f64;
+
+        fn index(&self, index: usize) -> &f64 {
+            &self.as_array()[index]
+        }
+    }
+
+    impl IndexMut<usize> for Vector4 {
+        fn index_mut(&mut self, index: usize) -> &mut f64 {
+            &mut self.as_mut_array()[index]
+        }
+    }
+
+    impl Index<Axis> for Vector4 {
+        type Output = f64;
+
+        fn index(&self, axis: Axis) -> &f64 {
+            &self[axis.index()]
+        }
+    }
+
+    impl IndexMut<Axis> for Vector4 {
+        fn index_mut(&mut self, axis: Axis) -> &mut f64 {
+            &mut self[axis.index()]
+        }
+    }
+
+
+    // ITERATION
+
+    impl Sum<Vector4> for Vector4 {
+        fn sum<I: Iterator<Item = Vector4>>(iter: I) -> Vector4 {
+            iter.fold(Vector4::new(0.0, 0.0, 0.0, 0.0), Add::add)
+        }
+    }
+
+    impl<'a> Sum<&'a Vector4> for Vector4 {
+        fn sum<I: Iterator<Item = &'a Vector4>>(iter: I) -> Vector4 {
+            iter.cloned().sum()
+        }
+    }
+
+    impl Product<Vector4> for Vector4 {
+        fn product<I: Iterator<Item = Vector4>>(iter: I) -> Vector4 {
+            iter.fold(Vector4::new(1.0, 1.0, 1.0, 1.0), Mul::mul)
+        }
+    }
+
+    impl<'a> Product<&'a Vector4> for Vector4 {
+        fn product<I: Iterator<Item = &'a Vector4>>(iter: I) -> Vector4 {
+            iter.cloned().product()
+        }
+    }
+
+    impl IntoIterator for Vector4 {
+        type Item = f64;
+        type IntoIter = array::IntoIter<f64, 4>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            IntoIterator::into_iter(*self.as_array())
+        }
+    }
+
+    impl<'a> IntoIterator for &'a Vector4 {
+        type Item = &'a f64;
+        type IntoIter = slice::Iter<'a, f64>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<'a> IntoIterator for &'a mut Vector4 {
+        type Item = &'a mut f64;
+        type IntoIter = slice::IterMut<'a, f64>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
+
     // CONVERSION
     impl From<[f64; 4]> for Vector4 {
         fn from(array: [f64; 4]) -> Self {
@@ -774,6 +1575,30 @@ mod vector4 {
             [vector.x, vector.y, vector.z, vector.w]
         }
     }
+
+    impl From<(f64, f64, f64, f64)> for Vector4 {
+        fn from((x, y, z, w): (f64, f64, f64, f64)) -> Self {
+            Vector4 { x, y, z, w }
+        }
+    }
+
+    impl From<Vector4> for (f64, f64, f64, f64) {
+        fn from(vector: Vector4) -> Self {
+            (vector.x, vector.y, vector.z, vector.w)
+        }
+    }
+
+    impl AsRef<[f64; 4]> for Vector4 {
+        fn as_ref(&self) -> &[f64; 4] {
+            self.as_array()
+        }
+    }
+
+    impl AsMut<[f64; 4]> for Vector4 {
+        fn as_mut(&mut self) -> &mut [f64; 4] {
+            self.as_mut_array()
+        }
+    }
 }
 
 
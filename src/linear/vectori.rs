@@ -3,17 +3,22 @@ pub use self::vector3i::Vector3i;
 pub use self::vector4i::Vector4i;
 
 mod vector2i {
-    use std::cmp::PartialEq;
     use std::ops::{
         Add, AddAssign,
         Sub, SubAssign,
         Mul, MulAssign,
         Div, DivAssign,
         Neg,
+        Index, IndexMut,
     };
+    use std::{array, slice};
+    use std::iter::{Sum, Product};
+    use super::super::Axis2;
 
-    #[derive(Copy, Clone, Hash, Eq)]
+    /// Ordered lexicographically by x, then y
+    #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
     #[derive(Debug)]
+    #[repr(C)]
     pub struct Vector2i {
         pub x: i64,
         pub y: i64,
@@ -31,18 +36,58 @@ mod vector2i {
         pub fn dot(&self, other: &Vector2i) -> i64 {
             self.x * other.x + self.y * other.y
         }
-    }
 
 
-    // COMPARISON
+        /// Component-wise minimum
+        pub fn component_min(&self, other: Vector2i) -> Vector2i {
+            Vector2i::new(self.x.min(other.x), self.y.min(other.y))
+        }
+
+        /// Component-wise maximum
+        pub fn component_max(&self, other: Vector2i) -> Vector2i {
+            Vector2i::new(self.x.max(other.x), self.y.max(other.y))
+        }
+
+        /// The component-wise minimum and maximum of points, or `None` if there are none
+        pub fn bounds<I: IntoIterator<Item = Vector2i>>(points: I) -> Option<(Vector2i, Vector2i)> {
+            let mut points = points.into_iter();
+            let first = points.next()?;
+            Some(points.fold((first, first), |(min, max), point| {
+                (min.component_min(point), max.component_max(point))
+            }))
+        }
+
+
+        /// View the components as an array
+        pub fn as_array(&self) -> &[i64; 2] {
+            // The struct is `repr(C)` with 2 fields of the same type, so it has the layout of an array
+            unsafe { &*(self as *const Vector2i as *const [i64; 2]) }
+        }
+
+        /// View the components as a mutable array
+        pub fn as_mut_array(&mut self) -> &mut [i64; 2] {
+            unsafe { &mut *(self as *mut Vector2i as *mut [i64; 2]) }
+        }
 
-    impl PartialEq<Vector2i> for Vector2i {
-        fn eq(&self, other: &Vector2i) -> bool {
-            self.x == other.x && self.y == other.y
+        /// Iterate over the components
+        pub fn iter(&self) -> slice::Iter<'_, i64> {
+            self.as_array().iter()
         }
 
-        fn ne(&self, other: &Vector2i) -> bool {
-            self.x != other.x || self.y != other.y
+        /// Iterate mutably over the components
+        pub fn iter_mut(&mut self) -> slice::IterMut<'_, i64> {
+            self.as_mut_array().iter_mut()
+        }
+
+        /// The axis of the component with the largest magnitude, the first on ties
+        pub fn dominant_axis(&self) -> Axis2 {
+            let mut dominant = 0;
+            for (i, component) in self.iter().enumerate() {
+                if component.unsigned_abs() > self[dominant].unsigned_abs() {
+                    dominant = i;
+                }
+            }
+            Axis2::ALL[dominant]
         }
     }
 
@@ -193,6 +238,90 @@ mod vector2i {
     }
 
 
+    // INDEXING
+
+    impl Index<usize> for Vector2i {
+        type Output = i64;
+
+        fn index(&self, index: usize) -> &i64 {
+            &self.as_array()[index]
+        }
+    }
+
+    impl IndexMut<usize> for Vector2i {
+        fn index_mut(&mut self, index: usize) -> &mut i64 {
+            &mut self.as_mut_array()[index]
+        }
+    }
+
+    impl Index<Axis2> for Vector2i {
+        type Output = i64;
+
+        fn index(&self, axis: Axis2) -> &i64 {
+            &self[axis.index()]
+        }
+    }
+
+    impl IndexMut<Axis2> for Vector2i {
+        fn index_mut(&mut self, axis: Axis2) -> &mut i64 {
+            &mut self[axis.index()]
+        }
+    }
+
+
+    // ITERATION
+
+    impl Sum<Vector2i> for Vector2i {
+        fn sum<I: Iterator<Item = Vector2i>>(iter: I) -> Vector2i {
+            iter.fold(Vector2i::new(0, 0), Add::add)
+        }
+    }
+
+    impl<'a> Sum<&'a Vector2i> for Vector2i {
+        fn sum<I: Iterator<Item = &'a Vector2i>>(iter: I) -> Vector2i {
+            iter.cloned().sum()
+        }
+    }
+
+    impl Product<Vector2i> for Vector2i {
+        fn product<I: Iterator<Item = Vector2i>>(iter: I) -> Vector2i {
+            iter.fold(Vector2i::new(1, 1), Mul::mul)
+        }
+    }
+
+    impl<'a> Product<&'a Vector2i> for Vector2i {
+        fn product<I: Iterator<Item = &'a Vector2i>>(iter: I) -> Vector2i {
+            iter.cloned().product()
+        }
+    }
+
+    impl IntoIterator for Vector2i {
+        type Item = i64;
+        type IntoIter = array::IntoIter<i64, 2>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            IntoIterator::into_iter(*self.as_array())
+        }
+    }
+
+    impl<'a> IntoIterator for &'a Vector2i {
+        type Item = &'a i64;
+        type IntoIter = slice::Iter<'a, i64>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<'a> IntoIterator for &'a mut Vector2i {
+        type Item = &'a mut i64;
+        type IntoIter = slice::IterMut<'a, i64>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
 
     // CONVERSION
     impl From<[i64; 2]> for Vector2i {
@@ -209,20 +338,49 @@ mod vector2i {
             [vector.x, vector.y]
         }
     }
+
+    impl From<(i64, i64)> for Vector2i {
+        fn from((x, y): (i64, i64)) -> Self {
+            Vector2i { x, y }
+        }
+    }
+
+    impl From<Vector2i> for (i64, i64) {
+        fn from(vector: Vector2i) -> Self {
+            (vector.x, vector.y)
+        }
+    }
+
+    impl AsRef<[i64; 2]> for Vector2i {
+        fn as_ref(&self) -> &[i64; 2] {
+            self.as_array()
+        }
+    }
+
+    impl AsMut<[i64; 2]> for Vector2i {
+        fn as_mut(&mut self) -> &mut [i64; 2] {
+            self.as_mut_array()
+        }
+    }
 }
 
 mod vector3i {
-    use std::cmp::PartialEq;
     use std::ops::{
         Add, AddAssign,
         Sub, SubAssign,
         Mul, MulAssign,
         Div, DivAssign,
         Neg,
+        Index, IndexMut,
     };
+    use std::{array, slice};
+    use std::iter::{Sum, Product};
+    use super::super::Axis3;
 
-    #[derive(Copy, Clone, Hash, Eq)]
+    /// Ordered lexicographically by x, then y, then z
+    #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
     #[derive(Debug)]
+    #[repr(C)]
     pub struct Vector3i {
         pub x: i64,
         pub y: i64,
@@ -241,18 +399,58 @@ mod vector3i {
         pub fn dot(&self, other: &Vector3i) -> i64 {
             self.x * other.x + self.y * other.y + self.z * other.z
         }
-    }
 
 
-    // COMPARISON
+        /// Component-wise minimum
+        pub fn component_min(&self, other: Vector3i) -> Vector3i {
+            Vector3i::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+        }
+
+        /// Component-wise maximum
+        pub fn component_max(&self, other: Vector3i) -> Vector3i {
+            Vector3i::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+        }
+
+        /// The component-wise minimum and maximum of points, or `None` if there are none
+        pub fn bounds<I: IntoIterator<Item = Vector3i>>(points: I) -> Option<(Vector3i, Vector3i)> {
+            let mut points = points.into_iter();
+            let first = points.next()?;
+            Some(points.fold((first, first), |(min, max), point| {
+                (min.component_min(point), max.component_max(point))
+            }))
+        }
+
+
+        /// View the components as an array
+        pub fn as_array(&self) -> &[i64; 3] {
+            // The struct is `repr(C)` with 3 fields of the same type, so it has the layout of an array
+            unsafe { &*(self as *const Vector3i as *const [i64; 3]) }
+        }
+
+        /// View the components as a mutable array
+        pub fn as_mut_array(&mut self) -> &mut [i64; 3] {
+            unsafe { &mut *(self as *mut Vector3i as *mut [i64; 3]) }
+        }
+
+        /// Iterate over the components
+        pub fn iter(&self) -> slice::Iter<'_, i64> {
+            self.as_array().iter()
+        }
 
-    impl PartialEq<Vector3i> for Vector3i {
-        fn eq(&self, other: &Vector3i) -> bool {
-            self.x == other.x && self.y == other.y && self.z == other.z
+        /// Iterate mutably over the components
+        pub fn iter_mut(&mut self) -> slice::IterMut<'_, i64> {
+            self.as_mut_array().iter_mut()
         }
 
-        fn ne(&self, other: &Vector3i) -> bool {
-            self.x != other.x || self.y != other.y || self.z != other.z
+        /// The axis of the component with the largest magnitude, the first on ties
+        pub fn dominant_axis(&self) -> Axis3 {
+            let mut dominant = 0;
+            for (i, component) in self.iter().enumerate() {
+                if component.unsigned_abs() > self[dominant].unsigned_abs() {
+                    dominant = i;
+                }
+            }
+            Axis3::ALL[dominant]
         }
     }
 
@@ -416,6 +614,91 @@ mod vector3i {
     }
 
 
+    // INDEXING
+
+    impl Index<usize> for Vector3i {
+        type Output = i64;
+
+        fn index(&self, index: usize) -> &i64 {
+            &self.as_array()[index]
+        }
+    }
+
+    impl IndexMut<usize> for Vector3i {
+        fn index_mut(&mut self, index: usize) -> &mut i64 {
+            &mut self.as_mut_array()[index]
+        }
+    }
+
+    impl Index<Axis3> for Vector3i {
+        type Output = i64;
+
+        fn index(&self, axis: Axis3) -> &i64 {
+            &self[axis.index()]
+        }
+    }
+
+    impl IndexMut<Axis3> for Vector3i {
+        fn index_mut(&mut self, axis: Axis3) -> &mut i64 {
+            &mut self[axis.index()]
+        }
+    }
+
+
+    // ITERATION
+
+    impl Sum<Vector3i> for Vector3i {
+        fn sum<I: Iterator<Item = Vector3i>>(iter: I) -> Vector3i {
+            iter.fold(Vector3i::new(0, 0, 0), Add::add)
+        }
+    }
+
+    impl<'a> Sum<&'a Vector3i> for Vector3i {
+        fn sum<I: Iterator<Item = &'a Vector3i>>(iter: I) -> Vector3i {
+            iter.cloned().sum()
+        }
+    }
+
+    impl Product<Vector3i> for Vector3i {
+        fn product<I: Iterator<Item = Vector3i>>(iter: I) -> Vector3i {
+            iter.fold(Vector3i::new(1, 1, 1), Mul::mul)
+        }
+    }
+
+    impl<'a> Product<&'a Vector3i> for Vector3i {
+        fn product<I: Iterator<Item = &'a Vector3i>>(iter: I) -> Vector3i {
+            iter.cloned().product()
+        }
+    }
+
+    impl IntoIterator for Vector3i {
+        type Item = i64;
+        type IntoIter = array::IntoIter<i64, 3>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            IntoIterator::into_iter(*self.as_array())
+        }
+    }
+
+    impl<'a> IntoIterator for &'a Vector3i {
+        type Item = &'a i64;
+        type IntoIter = slice::Iter<'a, i64>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<'a> IntoIterator for &'a mut Vector3i {
+        type Item = &'a mut i64;
+        type IntoIter = slice::IterMut<'a, i64>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
+
     // CONVERSION
     impl From<[i64; 3]> for Vector3i {
         fn from(array: [i64; 3]) -> Self {
@@ -432,20 +715,49 @@ mod vector3i {
             [vector.x, vector.y, vector.z]
         }
     }
+
+    impl From<(i64, i64, i64)> for Vector3i {
+        fn from((x, y, z): (i64, i64, i64)) -> Self {
+            Vector3i { x, y, z }
+        }
+    }
+
+    impl From<Vector3i> for (i64, i64, i64) {
+        fn from(vector: Vector3i) -> Self {
+            (vector.x, vector.y, vector.z)
+        }
+    }
+
+    impl AsRef<[i64; 3]> for Vector3i {
+        fn as_ref(&self) -> &[i64; 3] {
+            self.as_array()
+        }
+    }
+
+    impl AsMut<[i64; 3]> for Vector3i {
+        fn as_mut(&mut self) -> &mut [i64; 3] {
+            self.as_mut_array()
+        }
+    }
 }
 
 mod vector4i {
-    use std::cmp::PartialEq;
     use std::ops::{
         Add, AddAssign,
         Sub, SubAssign,
         Mul, MulAssign,
         Div, DivAssign,
         Neg,
+        Index, IndexMut,
     };
+    use std::{array, slice};
+    use std::iter::{Sum, Product};
+    use super::super::Axis;
 
-    #[derive(Copy, Clone, Hash, Eq)]
+    /// Ordered lexicographically by x, then y, then z, then w
+    #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
     #[derive(Debug)]
+    #[repr(C)]
     pub struct Vector4i {
         pub x: i64,
         pub y: i64,
@@ -464,18 +776,58 @@ mod vector4i {
         pub fn dot(&self, other: &Vector4i) -> i64 {
             self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
         }
-    }
 
 
-    // COMPARISON
+        /// Component-wise minimum
+        pub fn component_min(&self, other: Vector4i) -> Vector4i {
+            Vector4i::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z), self.w.min(other.w))
+        }
+
+        /// Component-wise maximum
+        pub fn component_max(&self, other: Vector4i) -> Vector4i {
+            Vector4i::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z), self.w.max(other.w))
+        }
+
+        /// The component-wise minimum and maximum of points, or `None` if there are none
+        pub fn bounds<I: IntoIterator<Item = Vector4i>>(points: I) -> Option<(Vector4i, Vector4i)> {
+            let mut points = points.into_iter();
+            let first = points.next()?;
+            Some(points.fold((first, first), |(min, max), point| {
+                (min.component_min(point), max.component_max(point))
+            }))
+        }
+
+
+        /// View the components as an array
+        pub fn as_array(&self) -> &[i64; 4] {
+            // The struct is `repr(C)` with 4 fields of the same type, so it has the layout of an array
+            unsafe { &*(self as *const Vector4i as *const [i64; 4]) }
+        }
 
-    impl PartialEq<Vector4i> for Vector4i {
-        fn eq(&self, other: &Vector4i) -> bool {
-            self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+        /// View the components as a mutable array
+        pub fn as_mut_array(&mut self) -> &mut [i64; 4] {
+            unsafe { &mut *(self as *mut Vector4i as *mut [i64; 4]) }
         }
 
-        fn ne(&self, other: &Vector4i) -> bool {
-            self.x != other.x || self.y != other.y || self.z != other.z || self.w != other.w
+        /// Iterate over the components
+        pub fn iter(&self) -> slice::Iter<'_, i64> {
+            self.as_array().iter()
+        }
+
+        /// Iterate mutably over the components
+        pub fn iter_mut(&mut self) -> slice::IterMut<'_, i64> {
+            self.as_mut_array().iter_mut()
+        }
+
+        /// The axis of the component with the largest magnitude, the first on ties
+        pub fn dominant_axis(&self) -> Axis {
+            let mut dominant = 0;
+            for (i, component) in self.iter().enumerate() {
+                if component.unsigned_abs() > self[dominant].unsigned_abs() {
+                    dominant = i;
+                }
+            }
+            Axis::ALL[dominant]
         }
     }
 
@@ -652,6 +1004,91 @@ mod vector4i {
     }
 
 
+    // INDEXING
+
+    impl Index<usize> for Vector4i {
+        type Output = i64;
+
+        fn index(&self, index: usize) -> &i64 {
+            &self.as_array()[index]
+        }
+    }
+
+    impl IndexMut<usize> for Vector4i {
+        fn index_mut(&mut self, index: usize) -> &mut i64 {
+            &mut self.as_mut_array()[index]
+        }
+    }
+
+    impl Index<Axis> for Vector4i {
+        type Output = i64;
+
+        fn index(&self, axis: Axis) -> &i64 {
+            &self[axis.index()]
+        }
+    }
+
+    impl IndexMut<Axis> for Vector4i {
+        fn index_mut(&mut self, axis: Axis) -> &mut i64 {
+            &mut self[axis.index()]
+        }
+    }
+
+
+    // ITERATION
+
+    impl Sum<Vector4i> for Vector4i {
+        fn sum<I: Iterator<Item = Vector4i>>(iter: I) -> Vector4i {
+            iter.fold(Vector4i::new(0, 0, 0, 0), Add::add)
+        }
+    }
+
+    impl<'a> Sum<&'a Vector4i> for Vector4i {
+        fn sum<I: Iterator<Item = &'a Vector4i>>(iter: I) -> Vector4i {
+            iter.cloned().sum()
+        }
+    }
+
+    impl Product<Vector4i> for Vector4i {
+        fn product<I: Iterator<Item = Vector4i>>(iter: I) -> Vector4i {
+            iter.fold(Vector4i::new(1, 1, 1, 1), Mul::mul)
+        }
+    }
+
+    impl<'a> Product<&'a Vector4i> for Vector4i {
+        fn product<I: Iterator<Item = &'a Vector4i>>(iter: I) -> Vector4i {
+            iter.cloned().product()
+        }
+    }
+
+    impl IntoIterator for Vector4i {
+        type Item = i64;
+        type IntoIter = array::IntoIter<i64, 4>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            IntoIterator::into_iter(*self.as_array())
+        }
+    }
+
+    impl<'a> IntoIterator for &'a Vector4i {
+        type Item = &'a i64;
+        type IntoIter = slice::Iter<'a, i64>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<'a> IntoIterator for &'a mut Vector4i {
+        type Item = &'a mut i64;
+        type IntoIter = slice::IterMut<'a, i64>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
+
     // CONVERSION
     impl From<[i64; 4]> for Vector4i {
         fn from(array: [i64; 4]) -> Self {
@@ -669,6 +1106,30 @@ mod vector4i {
             [vector.x, vector.y, vector.z, vector.w]
         }
     }
+
+    impl From<(i64, i64, i64, i64)> for Vector4i {
+        fn from((x, y, z, w): (i64, i64, i64, i64)) -> Self {
+            Vector4i { x, y, z, w }
+        }
+    }
+
+    impl From<Vector4i> for (i64, i64, i64, i64) {
+        fn from(vector: Vector4i) -> Self {
+            (vector.x, vector.y, vector.z, vector.w)
+        }
+    }
+
+    impl AsRef<[i64; 4]> for Vector4i {
+        fn as_ref(&self) -> &[i64; 4] {
+            self.as_array()
+        }
+    }
+
+    impl AsMut<[i64; 4]> for Vector4i {
+        fn as_mut(&mut self) -> &mut [i64; 4] {
+            self.as_mut_array()
+        }
+    }
 }
 
 
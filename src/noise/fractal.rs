@@ -0,0 +1,134 @@
+use std::ops::{Add, Mul};
+use super::Noise;
+
+/// Fractal Brownian motion, a sum of octaves of noise at increasing frequency and decreasing
+/// amplitude.
+///
+/// The sum is normalized by the total amplitude, so the range matches the underlying noise.
+/// Tileable noise stays tileable if the lacunarity is an integer.
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Fbm<N> {
+    pub noise: N,
+    pub octaves: u32,
+
+    /// Frequency multiplier between octaves
+    pub lacunarity: f64,
+
+    /// Amplitude multiplier between octaves
+    pub gain: f64,
+}
+
+
+/// Ridged multifractal noise, octaves of `(1 - |noise|)^2` giving sharp crests, in `[0, 1]`
+/// for noise in `[-1, 1]`.
+///
+/// The gradient jumps across the crests, where an octave of the noise crosses zero.
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Ridged<N> {
+    pub noise: N,
+    pub octaves: u32,
+    pub lacunarity: f64,
+    pub gain: f64,
+}
+
+
+/// Turbulence, octaves of `|noise|` giving billowy shapes, in `[0, 1]` for noise in `[-1, 1]`.
+///
+/// The gradient jumps across the creases, where an octave of the noise crosses zero.
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Turbulence<N> {
+    pub noise: N,
+    pub octaves: u32,
+    pub lacunarity: f64,
+    pub gain: f64,
+}
+
+
+/// Sum octaves of noise with each value passed through `shape`, which returns the shaped value
+/// and its derivative
+fn octaves<N, V, F>(noise: &N, point: V, octaves: u32, lacunarity: f64, gain: f64, shape: F) -> (f64, V)
+    where N: Noise<V>,
+          V: Copy + Add<V, Output = V> + Mul<f64, Output = V>,
+          F: Fn(f64) -> (f64, f64)
+{
+    let mut value = 0.0;
+    let mut gradient = point * 0.0;
+    let mut frequency = 1.0;
+    let mut amplitude = 1.0;
+    let mut total_amplitude = 0.0;
+
+    for _ in 0..octaves {
+        let (octave, octave_gradient) = noise.value_and_gradient(point * frequency);
+        let (shaped, slope) = shape(octave);
+
+        value += amplitude * shaped;
+        gradient = gradient + octave_gradient * (amplitude * slope * frequency);
+
+        total_amplitude += amplitude;
+        frequency *= lacunarity;
+        amplitude *= gain;
+    }
+
+    if total_amplitude == 0.0 {
+        return (value, gradient);
+    }
+    (value / total_amplitude, gradient * (1.0 / total_amplitude))
+}
+
+
+impl<N> Fbm<N> {
+    /// Create with a lacunarity of 2 and a gain of 0.5
+    pub fn new(noise: N, octaves: u32) -> Fbm<N> {
+        Fbm { noise, octaves, lacunarity: 2.0, gain: 0.5 }
+    }
+}
+
+impl<N> Ridged<N> {
+    /// Create with a lacunarity of 2 and a gain of 0.5
+    pub fn new(noise: N, octaves: u32) -> Ridged<N> {
+        Ridged { noise, octaves, lacunarity: 2.0, gain: 0.5 }
+    }
+}
+
+impl<N> Turbulence<N> {
+    /// Create with a lacunarity of 2 and a gain of 0.5
+    pub fn new(noise: N, octaves: u32) -> Turbulence<N> {
+        Turbulence { noise, octaves, lacunarity: 2.0, gain: 0.5 }
+    }
+}
+
+
+impl<N, V> Noise<V> for Fbm<N>
+    where N: Noise<V>,
+          V: Copy + Add<V, Output = V> + Mul<f64, Output = V>
+{
+    fn value_and_gradient(&self, point: V) -> (f64, V) {
+        octaves(&self.noise, point, self.octaves, self.lacunarity, self.gain, |value| (value, 1.0))
+    }
+}
+
+impl<N, V> Noise<V> for Ridged<N>
+    where N: Noise<V>,
+          V: Copy + Add<V, Output = V> + Mul<f64, Output = V>
+{
+    fn value_and_gradient(&self, point: V) -> (f64, V) {
+        octaves(&self.noise, point, self.octaves, self.lacunarity, self.gain, |value| {
+            let ridge = 1.0 - value.abs();
+            (ridge * ridge, -2.0 * ridge * value.signum())
+        })
+    }
+}
+
+impl<N, V> Noise<V> for Turbulence<N>
+    where N: Noise<V>,
+          V: Copy + Add<V, Output = V> + Mul<f64, Output = V>
+{
+    fn value_and_gradient(&self, point: V) -> (f64, V) {
+        octaves(&self.noise, point, self.octaves, self.lacunarity, self.gain, |value| {
+            (value.abs(), value.signum())
+        })
+    }
+}
@@ -0,0 +1,124 @@
+use std::num::NonZeroU32;
+use super::{hash, unit, gradient, dot};
+
+/// Perlin's improved gradient noise, in roughly `[-1, 1]` and zero at integer points
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct Perlin {
+    pub seed: u32,
+
+    /// Repeat every `period` units along each axis, if set
+    pub period: Option<NonZeroU32>,
+}
+
+
+/// Smoothly interpolated random values at integer points, in `[-1, 1]`
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct Value {
+    pub seed: u32,
+
+    /// Repeat every `period` units along each axis, if set
+    pub period: Option<NonZeroU32>,
+}
+
+
+/// Quintic smoothstep, with zero first and second derivatives at 0 and 1
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn fade_derivative(t: f64) -> f64 {
+    30.0 * t * t * (t - 1.0) * (t - 1.0)
+}
+
+
+/// Interpolate the contributions of the corners of the lattice cell containing a point.
+///
+/// `corner` gives the value and gradient of a corner from its hash and the offset of the point
+/// from it.
+#[allow(clippy::needless_range_loop)] // Indices run over several arrays at once
+fn lattice<const N: usize, F>(point: [f64; N], seed: u32, period: Option<NonZeroU32>, corner: F) -> (f64, [f64; N])
+    where F: Fn(u32, [f64; N]) -> (f64, [f64; N])
+{
+    let mut cell = [0; N];
+    let mut t = [0.0; N];
+    for i in 0..N {
+        let floor = point[i].floor();
+        cell[i] = floor as i64;
+        t[i] = point[i] - floor;
+    }
+
+    let mut value = 0.0;
+    let mut gradient = [0.0; N];
+    for mask in 0..1 << N {
+        let mut corner_cell = cell;
+        let mut offset = t;
+
+        // The interpolation weight of the corner and its partial derivatives
+        let mut weight = 1.0;
+        let mut weight_gradient = [1.0; N];
+
+        for i in 0..N {
+            let (w, dw) = if mask >> i & 1 == 1 {
+                corner_cell[i] += 1;
+                offset[i] -= 1.0;
+                (fade(t[i]), fade_derivative(t[i]))
+            } else {
+                (1.0 - fade(t[i]), -fade_derivative(t[i]))
+            };
+
+            weight *= w;
+            for j in 0..N {
+                weight_gradient[j] *= if i == j { dw } else { w };
+            }
+        }
+
+        let (c, dc) = corner(hash(seed, &corner_cell, period), offset);
+        value += weight * c;
+        for i in 0..N {
+            gradient[i] += weight * dc[i] + c * weight_gradient[i];
+        }
+    }
+
+    (value, gradient)
+}
+
+
+impl Perlin {
+    /// Create new noise
+    pub fn new(seed: u32) -> Perlin {
+        Perlin { seed, period: None }
+    }
+
+    /// Create noise repeating every `period` units along each axis. Panics if `period` is zero.
+    pub fn tileable(seed: u32, period: u32) -> Perlin {
+        let period = NonZeroU32::new(period).expect("period must be positive");
+        Perlin { seed, period: Some(period) }
+    }
+
+    pub(super) fn evaluate<const N: usize>(&self, point: [f64; N]) -> (f64, [f64; N]) {
+        lattice(point, self.seed, self.period, |hash, offset| {
+            let g = gradient::<N>(hash);
+            (dot(g, offset), g)
+        })
+    }
+}
+
+
+impl Value {
+    /// Create new noise
+    pub fn new(seed: u32) -> Value {
+        Value { seed, period: None }
+    }
+
+    /// Create noise repeating every `period` units along each axis. Panics if `period` is zero.
+    pub fn tileable(seed: u32, period: u32) -> Value {
+        let period = NonZeroU32::new(period).expect("period must be positive");
+        Value { seed, period: Some(period) }
+    }
+
+    pub(super) fn evaluate<const N: usize>(&self, point: [f64; N]) -> (f64, [f64; N]) {
+        lattice(point, self.seed, self.period, |hash, _| (unit(hash) * 2.0 - 1.0, [0.0; N]))
+    }
+}
@@ -0,0 +1,184 @@
+//! Coherent noise functions with analytical gradients.
+//!
+//! All noise is deterministic for a given seed. Lattice based noise can be made tileable by
+//! giving it a period, in which case it repeats every `period` units along each axis.
+use std::num::NonZeroU32;
+use linear::{Vector2, Vector3, Vector4};
+
+mod lattice;
+pub use self::lattice::{
+    Perlin,
+    Value,
+};
+
+mod simplex;
+pub use self::simplex::Simplex;
+
+mod worley;
+pub use self::worley::Worley;
+
+mod fractal;
+pub use self::fractal::{
+    Fbm,
+    Ridged,
+    Turbulence,
+};
+
+
+/// A noise function over points of type `V`
+pub trait Noise<V> {
+    /// The noise value at a point and its gradient
+    fn value_and_gradient(&self, point: V) -> (f64, V);
+
+    /// The noise value at a point
+    fn value(&self, point: V) -> f64 {
+        self.value_and_gradient(point).0
+    }
+}
+
+
+/// Finalizer of MurmurHash3, mixing all bits of the input
+fn mix(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^= h >> 16;
+    h
+}
+
+/// Hash of a lattice cell, wrapped to the period if there is one
+fn hash(seed: u32, cell: &[i64], period: Option<NonZeroU32>) -> u32 {
+    let mut h = mix(seed ^ 0x9e37_79b9);
+    for &c in cell {
+        let c = match period {
+            Some(period) => c.rem_euclid(period.get() as i64),
+            None => c,
+        };
+        h = mix(h ^ c as u32).wrapping_add((c >> 32) as u32);
+    }
+    mix(h)
+}
+
+/// A hash mapped to `[0, 1)`
+fn unit(hash: u32) -> f64 {
+    hash as f64 / 4_294_967_296.0
+}
+
+
+/// Midpoints of the edges of a cube, as in Perlin's improved noise
+const EDGES: [[f64; 3]; 12] = [
+    [1.0, 1.0, 0.0], [-1.0, 1.0, 0.0], [1.0, -1.0, 0.0], [-1.0, -1.0, 0.0],
+    [1.0, 0.0, 1.0], [-1.0, 0.0, 1.0], [1.0, 0.0, -1.0], [-1.0, 0.0, -1.0],
+    [0.0, 1.0, 1.0], [0.0, -1.0, 1.0], [0.0, 1.0, -1.0], [0.0, -1.0, -1.0],
+];
+
+/// Pseudo-random gradient in 2, 3 or 4 dimensions.
+///
+/// In 2 and 3 dimensions these are the cube edges (projected onto the plane), in 4 they are
+/// the 32 vectors with one zero and the other coordinates `±1`.
+fn gradient<const N: usize>(hash: u32) -> [f64; N] {
+    let mut g = [0.0; N];
+    if N == 4 {
+        let zero = (hash % 32 / 8) as usize;
+        let mut bit = 0;
+        for (i, value) in g.iter_mut().enumerate() {
+            if i != zero {
+                *value = if hash >> bit & 1 == 0 { 1.0 } else { -1.0 };
+                bit += 1;
+            }
+        }
+    } else {
+        g.copy_from_slice(&EDGES[(hash % 12) as usize][..N]);
+    }
+    g
+}
+
+fn dot<const N: usize>(a: [f64; N], b: [f64; N]) -> f64 {
+    a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+}
+
+
+// CONVERSION
+
+// Noise is implemented over arrays and exposed for the vector types
+
+fn evaluate<V, const N: usize, F>(point: V, noise: F) -> (f64, V)
+    where V: Into<[f64; N]> + From<[f64; N]>,
+          F: Fn([f64; N]) -> (f64, [f64; N])
+{
+    let (value, gradient) = noise(point.into());
+    (value, gradient.into())
+}
+
+impl Noise<Vector2> for Perlin {
+    fn value_and_gradient(&self, point: Vector2) -> (f64, Vector2) {
+        evaluate::<_, 2, _>(point, |p| self.evaluate(p))
+    }
+}
+
+impl Noise<Vector3> for Perlin {
+    fn value_and_gradient(&self, point: Vector3) -> (f64, Vector3) {
+        evaluate::<_, 3, _>(point, |p| self.evaluate(p))
+    }
+}
+
+impl Noise<Vector4> for Perlin {
+    fn value_and_gradient(&self, point: Vector4) -> (f64, Vector4) {
+        evaluate::<_, 4, _>(point, |p| self.evaluate(p))
+    }
+}
+
+impl Noise<Vector2> for Value {
+    fn value_and_gradient(&self, point: Vector2) -> (f64, Vector2) {
+        evaluate::<_, 2, _>(point, |p| self.evaluate(p))
+    }
+}
+
+impl Noise<Vector3> for Value {
+    fn value_and_gradient(&self, point: Vector3) -> (f64, Vector3) {
+        evaluate::<_, 3, _>(point, |p| self.evaluate(p))
+    }
+}
+
+impl Noise<Vector4> for Value {
+    fn value_and_gradient(&self, point: Vector4) -> (f64, Vector4) {
+        evaluate::<_, 4, _>(point, |p| self.evaluate(p))
+    }
+}
+
+impl Noise<Vector2> for Simplex {
+    fn value_and_gradient(&self, point: Vector2) -> (f64, Vector2) {
+        evaluate::<_, 2, _>(point, |p| self.evaluate(p))
+    }
+}
+
+impl Noise<Vector3> for Simplex {
+    fn value_and_gradient(&self, point: Vector3) -> (f64, Vector3) {
+        evaluate::<_, 3, _>(point, |p| self.evaluate(p))
+    }
+}
+
+impl Noise<Vector4> for Simplex {
+    fn value_and_gradient(&self, point: Vector4) -> (f64, Vector4) {
+        evaluate::<_, 4, _>(point, |p| self.evaluate(p))
+    }
+}
+
+impl Noise<Vector2> for Worley {
+    fn value_and_gradient(&self, point: Vector2) -> (f64, Vector2) {
+        evaluate::<_, 2, _>(point, |p| self.evaluate(p))
+    }
+}
+
+impl Noise<Vector3> for Worley {
+    fn value_and_gradient(&self, point: Vector3) -> (f64, Vector3) {
+        evaluate::<_, 3, _>(point, |p| self.evaluate(p))
+    }
+}
+
+impl Noise<Vector4> for Worley {
+    fn value_and_gradient(&self, point: Vector4) -> (f64, Vector4) {
+        evaluate::<_, 4, _>(point, |p| self.evaluate(p))
+    }
+}
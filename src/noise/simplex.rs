@@ -0,0 +1,89 @@
+use super::{hash, gradient, dot};
+
+/// Simplex noise, in roughly `[-1, 1]`.
+///
+/// Cheaper than Perlin noise in higher dimensions and without its axis-aligned artifacts. The
+/// skewed lattice does not line up with integer periods, so it cannot be tiled.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct Simplex {
+    pub seed: u32,
+}
+
+
+impl Simplex {
+    /// Create new noise
+    pub fn new(seed: u32) -> Simplex {
+        Simplex { seed }
+    }
+
+    #[allow(clippy::needless_range_loop)] // Indices run over several arrays at once
+    pub(super) fn evaluate<const N: usize>(&self, point: [f64; N]) -> (f64, [f64; N]) {
+        let n = N as f64;
+        let skew = ((n + 1.0).sqrt() - 1.0) / n;
+        let unskew = (1.0 - 1.0 / (n + 1.0).sqrt()) / n;
+
+        // A squared radius of influence of 0.5 keeps the kernels within neighbouring simplices,
+        // the larger 0.6 of Gustavson's reference implementation is discontinuous. The scales
+        // were measured to bring the extremes close to one.
+        let radius = 0.5;
+        let scale = match N {
+            2 => 70.0,
+            3 => 75.0,
+            _ => 60.0,
+        };
+
+        // Find the skewed hypercube, and the point's offset from its origin in normal space
+        let s = point.iter().sum::<f64>() * skew;
+        let mut cell = [0; N];
+        for i in 0..N {
+            cell[i] = (point[i] + s).floor() as i64;
+        }
+
+        let t = cell.iter().sum::<i64>() as f64 * unskew;
+        let mut origin_offset = [0.0; N];
+        for i in 0..N {
+            origin_offset[i] = point[i] - (cell[i] as f64 - t);
+        }
+
+        // The simplex is found by stepping along axes in decreasing order of offset
+        let mut order = [0; N];
+        for (i, axis) in order.iter_mut().enumerate() {
+            *axis = i;
+        }
+        order.sort_by(|&a, &b| origin_offset[b].total_cmp(&origin_offset[a]));
+
+        let mut value = 0.0;
+        let mut gradient_sum = [0.0; N];
+        let mut corner = cell;
+        for k in 0..=N {
+            if k > 0 {
+                corner[order[k - 1]] += 1;
+            }
+
+            let mut offset = [0.0; N];
+            for i in 0..N {
+                offset[i] = origin_offset[i] - (corner[i] - cell[i]) as f64 + k as f64 * unskew;
+            }
+
+            let falloff = radius - dot(offset, offset);
+            if falloff <= 0.0 {
+                continue;
+            }
+
+            let g = gradient::<N>(hash(self.seed, &corner, None));
+            let g_dot = dot(g, offset);
+            let falloff2 = falloff * falloff;
+
+            value += falloff2 * falloff2 * g_dot;
+            for i in 0..N {
+                gradient_sum[i] += falloff2 * falloff2 * g[i] - 8.0 * falloff2 * falloff * g_dot * offset[i];
+            }
+        }
+
+        for g in gradient_sum.iter_mut() {
+            *g *= scale;
+        }
+        (value * scale, gradient_sum)
+    }
+}
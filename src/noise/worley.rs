@@ -0,0 +1,71 @@
+use std::num::NonZeroU32;
+use super::{hash, unit, dot};
+
+/// Worley (cellular) noise, the distance to the nearest of one random feature point per unit
+/// cell.
+///
+/// Values are non-negative and rarely exceed one. The gradient points away from the nearest
+/// feature point, and is undefined at the feature point itself, where it is zero.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct Worley {
+    pub seed: u32,
+
+    /// Repeat every `period` units along each axis, if set
+    pub period: Option<NonZeroU32>,
+}
+
+
+impl Worley {
+    /// Create new noise
+    pub fn new(seed: u32) -> Worley {
+        Worley { seed, period: None }
+    }
+
+    /// Create noise repeating every `period` units along each axis. Panics if `period` is zero.
+    pub fn tileable(seed: u32, period: u32) -> Worley {
+        let period = NonZeroU32::new(period).expect("period must be positive");
+        Worley { seed, period: Some(period) }
+    }
+
+    pub(super) fn evaluate<const N: usize>(&self, point: [f64; N]) -> (f64, [f64; N]) {
+        let mut cell = [0; N];
+        for (c, p) in cell.iter_mut().zip(point.iter()) {
+            *c = p.floor() as i64;
+        }
+
+        let mut nearest = f64::INFINITY;
+        let mut nearest_offset = [0.0; N];
+
+        // The nearest feature point is in the cell or one of its neighbours
+        for index in 0..3usize.pow(N as u32) {
+            let mut neighbor = cell;
+            let mut rest = index;
+            for c in neighbor.iter_mut() {
+                *c += (rest % 3) as i64 - 1;
+                rest /= 3;
+            }
+
+            let h = hash(self.seed, &neighbor, self.period);
+            let mut offset = [0.0; N];
+            for (i, o) in offset.iter_mut().enumerate() {
+                let feature = neighbor[i] as f64 + unit(hash(h, &[i as i64], None));
+                *o = point[i] - feature;
+            }
+
+            let distance = dot(offset, offset);
+            if distance < nearest {
+                nearest = distance;
+                nearest_offset = offset;
+            }
+        }
+
+        let distance = nearest.sqrt();
+        if distance > 0.0 {
+            for o in nearest_offset.iter_mut() {
+                *o /= distance;
+            }
+        }
+        (distance, nearest_offset)
+    }
+}
@@ -0,0 +1,468 @@
+//! Random sampling of vectors, rotations and point sets, enabled by the `rand` feature.
+//!
+//! All distributions draw from a caller-supplied `Rng`, so a seeded generator gives
+//! reproducible samples.
+use std::f64::consts::PI;
+use rand::Rng;
+use rand::distributions::Distribution;
+use linear::{Vector2, Vector3, Quaternion, Unit, Aabb2, Aabb3};
+
+/// Uniformly distributed points on the unit circle
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct UnitCircle;
+
+
+/// Uniformly distributed points inside the unit disk
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct UnitDisk;
+
+
+/// Uniformly distributed points on the unit sphere, ie. random directions
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct UnitSphere;
+
+
+/// Uniformly distributed points inside the unit ball
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct UnitBall;
+
+
+/// Uniformly distributed directions in the hemisphere around a normal
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Hemisphere {
+    pub normal: Unit<Vector3>,
+}
+
+
+/// Directions in the hemisphere around a normal, with density proportional to the cosine of
+/// the angle to the normal, as used for importance sampling diffuse surfaces
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct CosineHemisphere {
+    pub normal: Unit<Vector3>,
+}
+
+
+/// Uniformly distributed points on a triangle in 2D or 3D
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct Triangle<V> {
+    pub a: V,
+    pub b: V,
+    pub c: V,
+}
+
+
+/// Uniformly distributed rotations
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct UniformRotation;
+
+
+/// Poisson-disk points in a rectangle, no two closer than `radius` and with no room for more,
+/// generated by Bridson's algorithm
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct PoissonDisk2 {
+    pub bounds: Aabb2,
+    pub radius: f64,
+
+    /// Candidates tried around a point before it is retired
+    pub attempts: u32,
+}
+
+
+/// Poisson-disk points in a box, no two closer than `radius` and with no room for more,
+/// generated by Bridson's algorithm
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct PoissonDisk3 {
+    pub bounds: Aabb3,
+    pub radius: f64,
+
+    /// Candidates tried around a point before it is retired
+    pub attempts: u32,
+}
+
+
+/// One point per cell of a grid over a rectangle, each displaced randomly within its cell
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct JitteredGrid2 {
+    pub bounds: Aabb2,
+    pub cells: [usize; 2],
+
+    /// Fraction of the cell a point may be displaced over, 0 for cell centers and 1 for
+    /// anywhere in the cell
+    pub jitter: f64,
+}
+
+
+/// One point per cell of a grid over a box, each displaced randomly within its cell
+#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug)]
+pub struct JitteredGrid3 {
+    pub bounds: Aabb3,
+    pub cells: [usize; 3],
+
+    /// Fraction of the cell a point may be displaced over, 0 for cell centers and 1 for
+    /// anywhere in the cell
+    pub jitter: f64,
+}
+
+
+impl Hemisphere {
+    /// Create a new hemisphere distribution
+    pub fn new(normal: Unit<Vector3>) -> Hemisphere {
+        Hemisphere { normal }
+    }
+}
+
+
+impl CosineHemisphere {
+    /// Create a new cosine-weighted hemisphere distribution
+    pub fn new(normal: Unit<Vector3>) -> CosineHemisphere {
+        CosineHemisphere { normal }
+    }
+}
+
+
+impl<V> Triangle<V> {
+    /// Create a new triangle distribution
+    pub fn new(a: V, b: V, c: V) -> Triangle<V> {
+        Triangle { a, b, c }
+    }
+}
+
+
+impl PoissonDisk2 {
+    /// Create with 30 attempts per point
+    pub fn new(bounds: Aabb2, radius: f64) -> PoissonDisk2 {
+        PoissonDisk2 { bounds, radius, attempts: 30 }
+    }
+
+    /// Generate the points. Empty if the bounds or radius are not finite, or if the bounds are
+    /// too large for the radius to fit the background grid in memory.
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<Vector2> {
+        let direction = |rng: &mut R| UnitCircle.sample(rng).into();
+        bridson(self.bounds.min.into(), self.bounds.max.into(), self.radius, self.attempts, rng, direction)
+            .into_iter()
+            .map(Vector2::from)
+            .collect()
+    }
+}
+
+
+impl PoissonDisk3 {
+    /// Create with 30 attempts per point
+    pub fn new(bounds: Aabb3, radius: f64) -> PoissonDisk3 {
+        PoissonDisk3 { bounds, radius, attempts: 30 }
+    }
+
+    /// Generate the points. Empty if the bounds or radius are not finite, or if the bounds are
+    /// too large for the radius to fit the background grid in memory.
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<Vector3> {
+        let direction = |rng: &mut R| UnitSphere.sample(rng).into();
+        bridson(self.bounds.min.into(), self.bounds.max.into(), self.radius, self.attempts, rng, direction)
+            .into_iter()
+            .map(Vector3::from)
+            .collect()
+    }
+}
+
+
+impl JitteredGrid2 {
+    /// Create with points anywhere in their cells
+    pub fn new(bounds: Aabb2, cells: [usize; 2]) -> JitteredGrid2 {
+        JitteredGrid2 { bounds, cells, jitter: 1.0 }
+    }
+
+    /// Generate the points, row by row
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<Vector2> {
+        let cell_size = self.bounds.size() / Vector2::new(self.cells[0] as f64, self.cells[1] as f64);
+
+        let mut points = Vec::with_capacity(self.cells[0] * self.cells[1]);
+        for y in 0..self.cells[1] {
+            for x in 0..self.cells[0] {
+                let offset = Vector2::new(jitter(rng, self.jitter), jitter(rng, self.jitter));
+                let cell = Vector2::new(x as f64, y as f64);
+                points.push(self.bounds.min + (cell + offset) * cell_size);
+            }
+        }
+        points
+    }
+}
+
+
+impl JitteredGrid3 {
+    /// Create with points anywhere in their cells
+    pub fn new(bounds: Aabb3, cells: [usize; 3]) -> JitteredGrid3 {
+        JitteredGrid3 { bounds, cells, jitter: 1.0 }
+    }
+
+    /// Generate the points, row by row and layer by layer
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<Vector3> {
+        let counts = Vector3::new(self.cells[0] as f64, self.cells[1] as f64, self.cells[2] as f64);
+        let cell_size = self.bounds.size() / counts;
+
+        let mut points = Vec::with_capacity(self.cells[0] * self.cells[1] * self.cells[2]);
+        for z in 0..self.cells[2] {
+            for y in 0..self.cells[1] {
+                for x in 0..self.cells[0] {
+                    let offset = Vector3::new(
+                        jitter(rng, self.jitter),
+                        jitter(rng, self.jitter),
+                        jitter(rng, self.jitter),
+                    );
+                    let cell = Vector3::new(x as f64, y as f64, z as f64);
+                    points.push(self.bounds.min + (cell + offset) * cell_size);
+                }
+            }
+        }
+        points
+    }
+}
+
+
+/// Barycentric weights of `b` and `c` for a uniform point on a triangle
+fn triangle_weights<R: Rng + ?Sized>(rng: &mut R) -> (f64, f64) {
+    let u: f64 = rng.gen();
+    let v: f64 = rng.gen();
+
+    // Fold the far half of the parallelogram back onto the triangle
+    if u + v > 1.0 {
+        (1.0 - u, 1.0 - v)
+    } else {
+        (u, v)
+    }
+}
+
+
+/// Position within a cell, in `[0, 1)`, displaced from the center by up to `amount / 2`
+fn jitter<R: Rng + ?Sized>(rng: &mut R, amount: f64) -> f64 {
+    0.5 + (rng.gen::<f64>() - 0.5) * amount
+}
+
+
+/// Largest background grid that Poisson-disk sampling allocates
+const MAX_GRID_CELLS: usize = 1 << 24;
+
+
+/// Bridson's Poisson-disk sampling between `min` and `max`.
+///
+/// A background grid with cells small enough to hold at most one point makes the distance
+/// check constant time. New points are tried at a uniformly random position in the shell
+/// between one and two radii around an active point.
+#[allow(clippy::needless_range_loop)] // Indices run over several arrays at once
+fn bridson<R, D, const N: usize>(min: [f64; N], max: [f64; N], radius: f64, attempts: u32, rng: &mut R, direction: D) -> Vec<[f64; N]>
+    where R: Rng + ?Sized,
+          D: Fn(&mut R) -> [f64; N]
+{
+    if !radius.is_finite() || radius <= 0.0 || (0..N).any(|i| !min[i].is_finite() || !max[i].is_finite() || max[i] < min[i]) {
+        return Vec::new();
+    }
+
+    let cell_size = radius / (N as f64).sqrt();
+    let mut dimensions = [0; N];
+    let mut cell_count: usize = 1;
+    for i in 0..N {
+        let cells = ((max[i] - min[i]) / cell_size).floor() + 1.0;
+        // Also catches an infinite extent from bounds near the ends of the float range
+        if cells > MAX_GRID_CELLS as f64 {
+            return Vec::new();
+        }
+        dimensions[i] = cells as usize;
+        cell_count = match cell_count.checked_mul(dimensions[i]) {
+            Some(count) if count <= MAX_GRID_CELLS => count,
+            _ => return Vec::new(),
+        };
+    }
+
+    let cell_of = |point: [f64; N]| {
+        let mut cell = [0; N];
+        for i in 0..N {
+            cell[i] = (((point[i] - min[i]) / cell_size) as usize).min(dimensions[i] - 1);
+        }
+        cell
+    };
+
+    let grid_index = |cell: [usize; N]| {
+        let mut index = 0;
+        for i in (0..N).rev() {
+            index = index * dimensions[i] + cell[i];
+        }
+        index
+    };
+
+    let mut grid = vec![usize::MAX; cell_count];
+    let mut points = Vec::new();
+    let mut active = Vec::new();
+
+    let mut first = [0.0; N];
+    for i in 0..N {
+        first[i] = rng.gen_range(min[i]..=max[i]);
+    }
+    grid[grid_index(cell_of(first))] = 0;
+    points.push(first);
+    active.push(0);
+
+    // A point at least one radius away can be at most two cells away along each axis
+    let neighborhood = 5usize.pow(N as u32);
+
+    while !active.is_empty() {
+        let slot = rng.gen_range(0..active.len());
+        let center = points[active[slot]];
+
+        let mut found = false;
+        for _ in 0..attempts {
+            // Uniform by volume: the N-th power of the distance is uniform
+            let scale = 2.0f64.powi(N as i32);
+            let distance = rng.gen_range(1.0..scale).powf(1.0 / N as f64) * radius;
+            let direction = direction(rng);
+
+            let mut candidate = [0.0; N];
+            for i in 0..N {
+                candidate[i] = center[i] + direction[i] * distance;
+            }
+            if (0..N).any(|i| candidate[i] < min[i] || candidate[i] > max[i]) {
+                continue;
+            }
+
+            let cell = cell_of(candidate);
+            let too_close = (0..neighborhood).any(|index| {
+                let mut neighbor = [0; N];
+                let mut rest = index;
+                for i in 0..N {
+                    let offset = (rest % 5) as isize - 2;
+                    rest /= 5;
+
+                    let coordinate = cell[i] as isize + offset;
+                    if coordinate < 0 || coordinate >= dimensions[i] as isize {
+                        return false;
+                    }
+                    neighbor[i] = coordinate as usize;
+                }
+
+                let other = grid[grid_index(neighbor)];
+                other != usize::MAX && {
+                    let other = points[other];
+                    let distance: f64 = (0..N).map(|i| (candidate[i] - other[i]).powi(2)).sum();
+                    distance < radius * radius
+                }
+            });
+
+            if !too_close {
+                grid[grid_index(cell)] = points.len();
+                active.push(points.len());
+                points.push(candidate);
+                found = true;
+                break;
+            }
+        }
+
+        if !found {
+            active.swap_remove(slot);
+        }
+    }
+
+    points
+}
+
+
+impl Distribution<Vector2> for UnitCircle {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+        Vector2::from_angle(rng.gen_range(0.0..2.0 * PI))
+    }
+}
+
+impl Distribution<Vector2> for UnitDisk {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+        // The area within a radius grows with its square
+        let radius = rng.gen::<f64>().sqrt();
+        UnitCircle.sample(rng) * radius
+    }
+}
+
+impl Distribution<Vector3> for UnitSphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        // By Archimedes' hat-box theorem, height on the sphere is uniform
+        let z: f64 = rng.gen_range(-1.0..=1.0);
+        let ring = UnitCircle.sample(rng) * (1.0 - z * z).sqrt();
+        Vector3::new(ring.x, ring.y, z)
+    }
+}
+
+impl Distribution<Vector3> for UnitBall {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        // The volume within a radius grows with its cube
+        let radius = rng.gen::<f64>().cbrt();
+        UnitSphere.sample(rng) * radius
+    }
+}
+
+impl Distribution<Vector3> for Hemisphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        let direction = UnitSphere.sample(rng);
+        if direction.dot(*self.normal) < 0.0 {
+            -direction
+        } else {
+            direction
+        }
+    }
+}
+
+impl Distribution<Vector3> for CosineHemisphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        // Malley's method, project a uniform point on the disk up onto the hemisphere
+        let disk = UnitDisk.sample(rng);
+        let height = (1.0 - disk.dot(disk)).max(0.0).sqrt();
+
+        let (tangent, bitangent) = self.normal.orthonormal_basis();
+        tangent * disk.x + bitangent * disk.y + *self.normal * height
+    }
+}
+
+impl Distribution<Vector2> for Aabb2 {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+        let t = Vector2::new(rng.gen(), rng.gen());
+        self.min + self.size() * t
+    }
+}
+
+impl Distribution<Vector3> for Aabb3 {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        let t = Vector3::new(rng.gen(), rng.gen(), rng.gen());
+        self.min + self.size() * t
+    }
+}
+
+impl Distribution<Vector2> for Triangle<Vector2> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+        let (u, v) = triangle_weights(rng);
+        self.a + (self.b - self.a) * u + (self.c - self.a) * v
+    }
+}
+
+impl Distribution<Vector3> for Triangle<Vector3> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        let (u, v) = triangle_weights(rng);
+        self.a + (self.b - self.a) * u + (self.c - self.a) * v
+    }
+}
+
+impl Distribution<Quaternion> for UniformRotation {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quaternion {
+        // Shoemake's method, a uniform point on the unit 3-sphere
+        let u: f64 = rng.gen();
+        let (sin_a, cos_a) = rng.gen_range(0.0..2.0 * PI).sin_cos();
+        let (sin_b, cos_b) = rng.gen_range(0.0..2.0 * PI).sin_cos();
+
+        let (r1, r2) = ((1.0 - u).sqrt(), u.sqrt());
+        Quaternion::new(r1 * sin_a, r1 * cos_a, r2 * sin_b, r2 * cos_b)
+    }
+}
@@ -0,0 +1,315 @@
+//! Low-discrepancy sequences, deterministic points covering the unit square or cube more
+//! evenly than random points
+use linear::{Vector2, Vector3};
+
+/// The digits of `index` in `base` mirrored around the decimal point, in `[0, 1)`. Panics if
+/// `base` is less than 2.
+pub fn radical_inverse(base: u32, mut index: u64) -> f64 {
+    assert!(base >= 2, "radical inverse base must be at least 2");
+
+    let base = base as u64;
+    let inverse_base = 1.0 / base as f64;
+
+    let mut result = 0.0;
+    let mut scale = inverse_base;
+    while index > 0 {
+        result += (index % base) as f64 * scale;
+        index /= base;
+        scale *= inverse_base;
+    }
+    result
+}
+
+
+/// The Halton sequence in bases 2 and 3, starting at the origin
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct Halton2 {
+    index: u64,
+}
+
+
+/// The Halton sequence in bases 2, 3 and 5, starting at the origin
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct Halton3 {
+    index: u64,
+}
+
+
+/// A fixed number of points, evenly spaced along x and with a base 2 radical inverse along y
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+pub struct Hammersley2 {
+    index: u64,
+    count: u64,
+}
+
+
+/// A fixed number of points, evenly spaced along x and with radical inverses in bases 2 and 3
+/// along y and z
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug)]
+pub struct Hammersley3 {
+    index: u64,
+    count: u64,
+}
+
+
+/// The Sobol sequence in two dimensions, starting at the origin.
+///
+/// Every power of two of consecutive points, aligned to a multiple of itself, has one point in
+/// each cell of the matching power of two grids.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct Sobol2 {
+    index: u64,
+    state: [u32; 2],
+}
+
+
+/// The Sobol sequence in three dimensions, starting at the origin
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct Sobol3 {
+    index: u64,
+    state: [u32; 3],
+}
+
+
+/// Roberts' R2 sequence, based on the plastic number.
+///
+/// Unlike Halton and Sobol it has no preferred number of points, and any run of consecutive
+/// points is well distributed.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
+pub struct R2 {
+    index: u64,
+}
+
+
+/// Sobol direction numbers for a primitive polynomial of degree `degree` with inner
+/// coefficients `coefficients`, and initial values `initial`
+const fn directions(degree: usize, coefficients: u32, initial: &[u32]) -> [u32; 32] {
+    let mut m = [0u32; 32];
+    let mut k = 0;
+    while k < 32 {
+        m[k] = if k < degree {
+            initial[k]
+        } else {
+            let mut value = m[k - degree] ^ (m[k - degree] << degree);
+            let mut i = 1;
+            while i < degree {
+                if coefficients >> (degree - 1 - i) & 1 == 1 {
+                    value ^= m[k - i] << i;
+                }
+                i += 1;
+            }
+            value
+        };
+        k += 1;
+    }
+
+    // Scale the odd integers m_k to binary fractions 0.m_k with k digits
+    let mut k = 0;
+    while k < 32 {
+        m[k] <<= 31 - k;
+        k += 1;
+    }
+    m
+}
+
+/// Direction numbers of the first dimension, which is the base 2 radical inverse
+const fn first_directions() -> [u32; 32] {
+    let mut v = [0u32; 32];
+    let mut k = 0;
+    while k < 32 {
+        v[k] = 1 << (31 - k);
+        k += 1;
+    }
+    v
+}
+
+/// Direction numbers for the first three dimensions, by Joe and Kuo
+const SOBOL_DIRECTIONS: [[u32; 32]; 3] = [
+    first_directions(),
+    directions(1, 0, &[1]),
+    directions(2, 1, &[1, 3]),
+];
+
+/// Advance the Sobol state of each dimension from `index` to `index + 1` in Gray code order
+fn sobol_step(index: u64, state: &mut [u32]) {
+    let bit = (!index).trailing_zeros() as usize;
+    if bit >= 32 {
+        return;
+    }
+
+    for (x, v) in state.iter_mut().zip(SOBOL_DIRECTIONS.iter()) {
+        *x ^= v[bit];
+    }
+}
+
+/// A 32 bit binary fraction as a float
+fn fraction(x: u32) -> f64 {
+    x as f64 / 4_294_967_296.0
+}
+
+
+impl Halton2 {
+    /// Create a new sequence
+    pub fn new() -> Halton2 {
+        Halton2::default()
+    }
+}
+
+impl Halton3 {
+    /// Create a new sequence
+    pub fn new() -> Halton3 {
+        Halton3::default()
+    }
+}
+
+impl Hammersley2 {
+    /// Create a set of `count` points
+    pub fn new(count: u64) -> Hammersley2 {
+        Hammersley2 { index: 0, count }
+    }
+}
+
+impl Hammersley3 {
+    /// Create a set of `count` points
+    pub fn new(count: u64) -> Hammersley3 {
+        Hammersley3 { index: 0, count }
+    }
+}
+
+impl Sobol2 {
+    /// Create a new sequence
+    pub fn new() -> Sobol2 {
+        Sobol2::default()
+    }
+}
+
+impl Sobol3 {
+    /// Create a new sequence
+    pub fn new() -> Sobol3 {
+        Sobol3::default()
+    }
+}
+
+impl R2 {
+    /// Create a new sequence
+    pub fn new() -> R2 {
+        R2::default()
+    }
+}
+
+
+impl Iterator for Halton2 {
+    type Item = Vector2;
+
+    fn next(&mut self) -> Option<Vector2> {
+        let i = self.index;
+        self.index += 1;
+        Some(Vector2::new(radical_inverse(2, i), radical_inverse(3, i)))
+    }
+}
+
+impl Iterator for Halton3 {
+    type Item = Vector3;
+
+    fn next(&mut self) -> Option<Vector3> {
+        let i = self.index;
+        self.index += 1;
+        Some(Vector3::new(radical_inverse(2, i), radical_inverse(3, i), radical_inverse(5, i)))
+    }
+}
+
+impl Iterator for Hammersley2 {
+    type Item = Vector2;
+
+    fn next(&mut self) -> Option<Vector2> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let i = self.index;
+        self.index += 1;
+        Some(Vector2::new(i as f64 / self.count as f64, radical_inverse(2, i)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.count - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Hammersley2 {}
+
+impl Iterator for Hammersley3 {
+    type Item = Vector3;
+
+    fn next(&mut self) -> Option<Vector3> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let i = self.index;
+        self.index += 1;
+        Some(Vector3::new(i as f64 / self.count as f64, radical_inverse(2, i), radical_inverse(3, i)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.count - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Hammersley3 {}
+
+impl Iterator for Sobol2 {
+    type Item = Vector2;
+
+    // The sequence ends after 2^32 points, when the 32 bit state is exhausted
+    fn next(&mut self) -> Option<Vector2> {
+        if self.index >= 1 << 32 {
+            return None;
+        }
+
+        let point = Vector2::new(fraction(self.state[0]), fraction(self.state[1]));
+        sobol_step(self.index, &mut self.state);
+        self.index += 1;
+        Some(point)
+    }
+}
+
+impl Iterator for Sobol3 {
+    type Item = Vector3;
+
+    fn next(&mut self) -> Option<Vector3> {
+        if self.index >= 1 << 32 {
+            return None;
+        }
+
+        let point = Vector3::new(fraction(self.state[0]), fraction(self.state[1]), fraction(self.state[2]));
+        sobol_step(self.index, &mut self.state);
+        self.index += 1;
+        Some(point)
+    }
+}
+
+impl Iterator for R2 {
+    type Item = Vector2;
+
+    fn next(&mut self) -> Option<Vector2> {
+        // The plastic number, the unique real solution of x^3 = x + 1
+        const PLASTIC: f64 = 1.324_717_957_244_746;
+
+        let n = self.index as f64;
+        self.index += 1;
+
+        let x = 0.5 + n / PLASTIC;
+        let y = 0.5 + n / (PLASTIC * PLASTIC);
+        Some(Vector2::new(x.fract(), y.fract()))
+    }
+}